@@ -0,0 +1,52 @@
+use alloc::vec::Vec;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{DynArray, PartialInit};
+
+/// Length-prefixed: the length is drawn up front with `arbitrary_len` (which reads it from the end
+/// of the input) before any element, so the byte format differs from `Vec`'s `arbitrary_iter`
+/// encoding even though `size_hint` delegates to `Vec`
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for DynArray<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<T>()?;
+        let mut guard = PartialInit::new(len);
+
+        while !guard.is_full() {
+            guard.push(T::arbitrary(u)?);
+        }
+
+        Ok(guard.finish())
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary_take_rest(u).map(DynArray::from)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Vec::<T>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::DynArray;
+
+    #[test]
+    fn from_bytes_test() {
+        let bytes = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 6];
+        let mut u = Unstructured::new(&bytes);
+
+        let a = DynArray::<u16>::arbitrary(&mut u).unwrap();
+
+        // the length comes from the last byte, the elements from the front
+        assert_eq!(&*a, &[0x0201, 0x0403, 0x0605]);
+
+        let b = DynArray::<u8>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        let v = Vec::<u8>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        assert_eq!(&*b, &v[..]);
+    }
+}
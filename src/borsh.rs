@@ -0,0 +1,115 @@
+use alloc::vec::Vec;
+
+use borsh::error::ERROR_ZST_FORBIDDEN;
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{is_cautious_len, DynArray, PartialInit};
+
+/// Encoded exactly like `Vec<T>`: a `u32` length prefix followed by the elements
+impl<T: BorshSerialize> BorshSerialize for DynArray<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        if core::mem::size_of::<T>() == 0 {
+            return Err(Error::new(ErrorKind::InvalidData, ERROR_ZST_FORBIDDEN));
+        }
+
+        let len = u32::try_from(self.len()).map_err(|_| ErrorKind::InvalidData)?;
+        len.serialize(writer)?;
+
+        for elem in self.iter() {
+            elem.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for DynArray<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        // like borsh's `Vec`, a huge count of zero-sized elements would cost no input to claim
+        if core::mem::size_of::<T>() == 0 {
+            return Err(Error::new(ErrorKind::InvalidData, ERROR_ZST_FORBIDDEN));
+        }
+
+        let len = u32::deserialize_reader(reader)?;
+        let len = usize::try_from(len).map_err(|_| Error::from(ErrorKind::InvalidData))?;
+
+        // the length prefix is untrusted, so only allocate it at once if that's cheap
+        if !is_cautious_len::<T>(len) {
+            let mut vec = Vec::new();
+
+            for _ in 0..len {
+                vec.push(T::deserialize_reader(reader)?);
+            }
+
+            return Ok(DynArray::from(vec));
+        }
+
+        let mut guard = PartialInit::new(len);
+
+        while !guard.is_full() {
+            guard.push(T::deserialize_reader(reader)?);
+        }
+
+        Ok(guard.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DynArray;
+
+    #[test]
+    fn round_trip_test() {
+        let a = DynArray::from(["a".to_string(), "bc".to_string()]);
+
+        let bytes = borsh::to_vec(&a).unwrap();
+        let b: DynArray<String> = borsh::from_slice(&bytes).unwrap();
+
+        assert_eq!(&*a, &*b);
+    }
+
+    #[test]
+    fn matches_vec_test() {
+        let a = DynArray::from([1u32, 2, 3]);
+        let v = vec![1u32, 2, 3];
+
+        assert_eq!(borsh::to_vec(&a).unwrap(), borsh::to_vec(&v).unwrap());
+
+        let bytes = DynArray::from([7u8, 8, 9]);
+        assert_eq!(
+            borsh::to_vec(&bytes).unwrap(),
+            borsh::to_vec(&vec![7u8, 8, 9]).unwrap()
+        );
+    }
+
+    #[test]
+    fn truncated_input_test() {
+        let v = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let bytes = borsh::to_vec(&v).unwrap();
+
+        let result = borsh::from_slice::<DynArray<String>>(&bytes[..bytes.len() - 3]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn huge_length_prefix_test() {
+        let result = borsh::from_slice::<DynArray<[u64; 1024]>>(&[0xff; 4]);
+        assert!(result.is_err());
+
+        let mut bytes = borsh::to_vec(&(1u32 << 20)).unwrap();
+        bytes.extend_from_slice(&[1, 2, 3]);
+        assert!(borsh::from_slice::<DynArray<u8>>(&bytes).is_err());
+    }
+
+    #[test]
+    fn zero_sized_test() {
+        let bytes = borsh::to_vec(&3u32).unwrap();
+
+        assert!(borsh::from_slice::<Vec<()>>(&bytes).is_err());
+        assert!(borsh::from_slice::<DynArray<()>>(&bytes).is_err());
+
+        assert!(borsh::to_vec(&vec![(), (), ()]).is_err());
+        assert!(borsh::to_vec(&DynArray::from([(), (), ()])).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use core::mem::{align_of, size_of};
+
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+use crate::{alloc_buffer_zeroed, DynArray};
+
+impl<T: Zeroable> DynArray<T> {
+    /// allocate new DynArray of size `len` with all bytes set to zero
+    ///
+    /// `DynArray` itself can't be `Zeroable`, since an all-zero DynArray would hold a null pointer.
+    #[must_use]
+    pub fn zeroed(len: usize) -> Self {
+        unsafe { DynArray::from_parts(alloc_buffer_zeroed(len), len) }
+    }
+}
+
+impl<T: Pod> DynArray<T> {
+    /// reinterpret the buffer as elements of type `U` without copying
+    ///
+    /// Like `bytemuck::try_cast_vec`, this requires `T` and `U` to have the same alignment,
+    /// since the allocation is later freed with the layout of `U`, and the byte length to be a
+    /// multiple of `size_of::<U>()`. On failure the original array is handed back.
+    pub fn cast<U: Pod>(self) -> Result<DynArray<U>, (PodCastError, DynArray<T>)> {
+        if align_of::<T>() != align_of::<U>() {
+            return Err((PodCastError::AlignmentMismatch, self));
+        }
+
+        if size_of::<T>() == size_of::<U>() {
+            let (ptr, len) = self.into_parts();
+            return Ok(unsafe { DynArray::from_parts(ptr as *mut U, len) });
+        }
+
+        if size_of::<T>() == 0 || size_of::<U>() == 0 {
+            return Err((PodCastError::SizeMismatch, self));
+        }
+
+        let byte_len = self.len() * size_of::<T>();
+
+        if !byte_len.is_multiple_of(size_of::<U>()) {
+            return Err((PodCastError::OutputSliceWouldHaveSlop, self));
+        }
+
+        let (ptr, _) = self.into_parts();
+        Ok(unsafe { DynArray::from_parts(ptr as *mut U, byte_len / size_of::<U>()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::PodCastError;
+
+    use crate::DynArray;
+
+    #[test]
+    fn zeroed_test() {
+        let a = DynArray::<u64>::zeroed(16);
+
+        assert_eq!(a.len(), 16);
+        assert!(a.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn cast_test() {
+        let a = DynArray::from([1u8, 2, 3, 4, 5, 6, 7, 8]);
+        let b: DynArray<[u8; 4]> = a.cast().unwrap();
+
+        assert_eq!(&*b, &[[1, 2, 3, 4], [5, 6, 7, 8]]);
+
+        let c: DynArray<u8> = b.cast().unwrap();
+        assert_eq!(&*c, &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let d = DynArray::from([1.0f32, 2.0]);
+        let e: DynArray<u32> = d.cast().unwrap();
+        assert_eq!(&*e, &[1.0f32.to_bits(), 2.0f32.to_bits()]);
+    }
+
+    #[test]
+    fn cast_reject_test() {
+        let a = DynArray::from([1u8, 2, 3, 4]);
+        let (err, a) = a.cast::<u32>().unwrap_err();
+        assert_eq!(err, PodCastError::AlignmentMismatch);
+
+        let a = DynArray::from([a[0], a[1], a[2]]);
+        let (err, a) = a.cast::<[u8; 2]>().unwrap_err();
+        assert_eq!(err, PodCastError::OutputSliceWouldHaveSlop);
+        assert_eq!(&*a, &[1, 2, 3]);
+    }
+}
@@ -0,0 +1,31 @@
+use bytes::Bytes;
+
+use crate::DynArray;
+
+/// Hands the allocation over to `Bytes` without copying
+impl From<DynArray<u8>> for Bytes {
+    #[inline]
+    fn from(dyn_array: DynArray<u8>) -> Self {
+        Bytes::from(dyn_array.into_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use crate::DynArray;
+
+    #[test]
+    fn into_bytes_test() {
+        let a = DynArray::from(*b"hello world");
+        let ptr = a.as_ptr();
+
+        let bytes = Bytes::from(a);
+
+        assert_eq!(&bytes[..], b"hello world");
+        assert_eq!(bytes.as_ptr(), ptr);
+
+        assert!(Bytes::from(DynArray::<u8>::default()).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+use crate::DynArray;
+
+impl<T> DynArray<T> {
+    /// split into owned chunks of `chunk_size` elements, starting at the end
+    ///
+    /// The last chunk yielded (the front of the array) is shorter if `chunk_size` doesn't divide
+    /// the length. Elements are moved, not cloned. Panics if `chunk_size` is 0.
+    pub fn into_rchunks(self, chunk_size: usize) -> RChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let (ptr, len) = self.into_parts();
+        let dyn_array = unsafe { DynArray::from_parts(ptr as *mut ManuallyDrop<T>, len) };
+
+        RChunks {
+            dyn_array,
+            end: len,
+            chunk_size,
+        }
+    }
+
+    /// split into owned chunks of exactly `chunk_size` elements, starting at the front
+    ///
+    /// The elements that don't fill a full chunk are available through
+    /// [`ChunksExact::remainder`]. Elements are moved, not cloned. Panics if `chunk_size` is 0.
+    pub fn into_chunks_exact(self, chunk_size: usize) -> ChunksExact<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let (ptr, len) = self.into_parts();
+        let dyn_array = unsafe { DynArray::from_parts(ptr as *mut ManuallyDrop<T>, len) };
+
+        ChunksExact {
+            dyn_array,
+            start: 0,
+            end: len,
+            chunk_size,
+        }
+    }
+}
+
+/// Owning iterator over chunks from the back, see [`DynArray::into_rchunks`]
+pub struct RChunks<T> {
+    dyn_array: DynArray<ManuallyDrop<T>>,
+    /// elements `0..end` haven't been yielded yet
+    end: usize,
+    chunk_size: usize,
+}
+
+impl<T> Iterator for RChunks<T> {
+    type Item = DynArray<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end == 0 {
+            return None;
+        }
+
+        let start = self.end.saturating_sub(self.chunk_size);
+        let src = unsafe { self.dyn_array.ptr.add(start) as *const T };
+        let chunk = unsafe { DynArray::move_from_raw(src, self.end - start) };
+
+        self.end = start;
+
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end.div_ceil(self.chunk_size);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for RChunks<T> {}
+
+impl<T> Drop for RChunks<T> {
+    fn drop(&mut self) {
+        let remaining = ptr::slice_from_raw_parts_mut(self.dyn_array.ptr as *mut T, self.end);
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
+
+/// Owning iterator over chunks of exactly equal length, see [`DynArray::into_chunks_exact`]
+pub struct ChunksExact<T> {
+    dyn_array: DynArray<ManuallyDrop<T>>,
+    /// elements `start..end` haven't been yielded yet
+    start: usize,
+    end: usize,
+    chunk_size: usize,
+}
+
+impl<T> ChunksExact<T> {
+    /// move out the elements that don't fill a full chunk, dropping any full chunks not yet
+    /// yielded
+    pub fn remainder(mut self) -> DynArray<T> {
+        let remainder_start = self.dyn_array.len - self.dyn_array.len % self.chunk_size;
+        let src = unsafe { self.dyn_array.ptr.add(remainder_start) as *const T };
+        let remainder = unsafe { DynArray::move_from_raw(src, self.end - remainder_start) };
+
+        self.end = remainder_start;
+
+        remainder
+    }
+}
+
+impl<T> Iterator for ChunksExact<T> {
+    type Item = DynArray<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.dyn_array.len - self.start < self.chunk_size {
+            return None;
+        }
+
+        let src = unsafe { self.dyn_array.ptr.add(self.start) as *const T };
+        let chunk = unsafe { DynArray::move_from_raw(src, self.chunk_size) };
+
+        self.start += self.chunk_size;
+
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.dyn_array.len - self.start) / self.chunk_size;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for ChunksExact<T> {}
+
+impl<T> Drop for ChunksExact<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = self.dyn_array.ptr.add(self.start) as *mut T;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                remaining,
+                self.end - self.start,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::DynArray;
+
+    #[test]
+    fn into_rchunks_test() {
+        let chunks: Vec<DynArray<i32>> = DynArray::from([1, 2, 3, 4, 5]).into_rchunks(2).collect();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(&*chunks[0], &[4, 5]);
+        assert_eq!(&*chunks[1], &[2, 3]);
+        assert_eq!(&*chunks[2], &[1]);
+
+        assert_eq!(DynArray::<i32>::default().into_rchunks(2).count(), 0);
+    }
+
+    #[test]
+    fn into_rchunks_drop_test() {
+        let rc = Rc::new(());
+        let a = DynArray::from_elem(rc.clone(), 5);
+
+        let mut chunks = a.into_rchunks(2);
+        assert_eq!(chunks.len(), 3);
+
+        let last = chunks.next().unwrap();
+        drop(chunks);
+
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(last);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn into_rchunks_zero_test() {
+        let _ = DynArray::from([1, 2]).into_rchunks(0);
+    }
+
+    #[test]
+    fn into_chunks_exact_test() {
+        let mut chunks = DynArray::from([1, 2, 3, 4, 5]).into_chunks_exact(2);
+        assert_eq!(chunks.len(), 2);
+
+        assert_eq!(&*chunks.next().unwrap(), &[1, 2]);
+        assert_eq!(&*chunks.next().unwrap(), &[3, 4]);
+        assert!(chunks.next().is_none());
+
+        assert_eq!(&*chunks.remainder(), &[5]);
+    }
+
+    #[test]
+    fn into_chunks_exact_drop_test() {
+        let rc = Rc::new(());
+        let a = DynArray::from_elem(rc.clone(), 7);
+
+        let mut chunks = a.into_chunks_exact(3);
+        let first = chunks.next().unwrap();
+
+        let remainder = chunks.remainder();
+        assert_eq!(remainder.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        drop(first);
+        drop(remainder);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        drop(DynArray::from_elem(rc.clone(), 7).into_chunks_exact(3));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,38 @@
+//! C-callable functions for creating and destroying byte arrays
+//!
+//! The returned `DynArray<u8>` is `#[repr(C)]`, i.e. `struct { uint8_t *ptr; size_t len; }`. Its
+//! memory belongs to Rust's allocator: C must hand it back to [`dynarray_u8_free`] and must never
+//! `free()` the pointer itself.
+
+use crate::DynArray;
+
+/// allocate a zeroed byte array of length `len`
+#[no_mangle]
+pub extern "C" fn dynarray_u8_with_len(len: usize) -> DynArray<u8> {
+    DynArray::new(len)
+}
+
+/// free a byte array created by [`dynarray_u8_with_len`]
+#[no_mangle]
+pub extern "C" fn dynarray_u8_free(arr: DynArray<u8>) {
+    drop(arr);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dynarray_u8_free, dynarray_u8_with_len};
+
+    #[test]
+    fn round_trip_test() {
+        let mut arr = dynarray_u8_with_len(32);
+
+        assert_eq!(arr.len(), 32);
+        assert!(arr.iter().all(|&x| x == 0));
+
+        arr[31] = 7;
+        assert_eq!(arr[31], 7);
+
+        dynarray_u8_free(arr);
+        dynarray_u8_free(dynarray_u8_with_len(0));
+    }
+}
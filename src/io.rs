@@ -0,0 +1,251 @@
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+use crate::DynArray;
+
+/// Writes into a `DynArray<u8>` like a bounded buffer
+///
+/// The array is never reallocated: once `len` bytes have been written, further writes are short
+/// (eventually writing 0 bytes), just like writing into a `&mut [u8]`.
+#[derive(Debug)]
+pub struct DynArrayWriter {
+    dyn_array: DynArray<u8>,
+    pos: usize,
+}
+
+impl DynArrayWriter {
+    #[inline]
+    pub fn new(dyn_array: DynArray<u8>) -> Self {
+        DynArrayWriter { dyn_array, pos: 0 }
+    }
+
+    /// number of bytes written so far
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DynArray<u8> {
+        self.dyn_array
+    }
+}
+
+impl Write for DynArrayWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let remaining = &mut self.dyn_array[self.pos..];
+        let n = remaining.len().min(buf.len());
+
+        remaining[..n].copy_from_slice(&buf[..n]);
+        self.pos += n;
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Reads the contents of a `DynArray<u8>` front to back
+#[derive(Debug)]
+pub struct DynArrayReader {
+    dyn_array: DynArray<u8>,
+    pos: usize,
+}
+
+impl DynArrayReader {
+    #[inline]
+    pub fn new(dyn_array: DynArray<u8>) -> Self {
+        DynArrayReader { dyn_array, pos: 0 }
+    }
+
+    /// number of bytes read so far
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DynArray<u8> {
+        self.dyn_array
+    }
+}
+
+impl Read for DynArrayReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = &self.dyn_array[self.pos..];
+        let n = remaining.len().min(buf.len());
+
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+
+        Ok(n)
+    }
+}
+
+/// A seekable, bounded cursor over a `DynArray<u8>`, like `std::io::Cursor<Vec<u8>>` but never
+/// growing the array
+///
+/// The position may be set past the end; reads there return 0 bytes and writes write 0 bytes.
+#[derive(Debug)]
+pub struct DynArrayCursor {
+    dyn_array: DynArray<u8>,
+    pos: u64,
+}
+
+impl DynArrayCursor {
+    #[inline]
+    pub fn new(dyn_array: DynArray<u8>) -> Self {
+        DynArrayCursor { dyn_array, pos: 0 }
+    }
+
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    #[inline]
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DynArray<u8> {
+        self.dyn_array
+    }
+
+    /// index of the position, clamped to the array
+    #[inline]
+    fn offset(&self) -> usize {
+        usize::try_from(self.pos).map_or(self.dyn_array.len(), |pos| pos.min(self.dyn_array.len()))
+    }
+}
+
+impl Read for DynArrayCursor {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = &self.dyn_array[self.offset()..];
+        let n = remaining.len().min(buf.len());
+
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n as u64;
+
+        Ok(n)
+    }
+}
+
+impl Write for DynArrayCursor {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let offset = self.offset();
+        let remaining = &mut self.dyn_array[offset..];
+        let n = remaining.len().min(buf.len());
+
+        remaining[..n].copy_from_slice(&buf[..n]);
+        self.pos += n as u64;
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for DynArrayCursor {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(pos) => {
+                self.pos = pos;
+                return Ok(pos);
+            }
+            SeekFrom::End(offset) => (self.dyn_array.len() as u64, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(pos) => {
+                self.pos = pos;
+                Ok(pos)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+
+    use crate::{DynArray, DynArrayCursor, DynArrayReader, DynArrayWriter};
+
+    #[test]
+    fn write_test() {
+        let mut writer = DynArrayWriter::new(DynArray::new(16));
+
+        writer.write_all("hello world".as_bytes()).unwrap();
+        assert_eq!(writer.position(), 11);
+
+        let a = writer.into_inner();
+        assert_eq!(&a[..11], b"hello world");
+        assert_eq!(&a[11..], &[0; 5]);
+    }
+
+    #[test]
+    fn short_write_test() {
+        let mut writer = DynArrayWriter::new(DynArray::new(4));
+
+        assert_eq!(writer.write(b"abcdef").unwrap(), 4);
+        assert_eq!(writer.write(b"gh").unwrap(), 0);
+        assert_eq!(
+            writer.write_all(b"gh").unwrap_err().kind(),
+            ErrorKind::WriteZero
+        );
+
+        assert_eq!(&*writer.into_inner(), b"abcd");
+    }
+
+    #[test]
+    fn read_test() {
+        let mut reader = DynArrayReader::new(DynArray::from(*b"hello world"));
+
+        let mut first = [0; 6];
+        reader.read_exact(&mut first).unwrap();
+        assert_eq!(&first, b"hello ");
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"world");
+        assert_eq!(reader.position(), 11);
+
+        assert_eq!(reader.read(&mut first).unwrap(), 0);
+    }
+
+    #[test]
+    fn cursor_test() {
+        let mut cursor = DynArrayCursor::new(DynArray::new(8));
+
+        assert_eq!(cursor.seek(SeekFrom::Start(3)).unwrap(), 3);
+        cursor.write_all(b"abc").unwrap();
+        assert_eq!(cursor.position(), 6);
+
+        assert_eq!(cursor.seek(SeekFrom::Current(-3)).unwrap(), 3);
+        let mut buf = [0; 3];
+        cursor.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"abc");
+
+        assert_eq!(cursor.seek(SeekFrom::End(-1)).unwrap(), 7);
+        assert_eq!(cursor.write(b"xyz").unwrap(), 1);
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+
+        assert!(cursor.seek(SeekFrom::Current(-9)).is_err());
+
+        cursor.set_position(100);
+        assert_eq!(cursor.write(b"xyz").unwrap(), 0);
+
+        assert_eq!(&*cursor.into_inner(), b"\0\0\0abc\0x");
+    }
+}
@@ -1,10 +1,68 @@
-use std::alloc::{alloc, dealloc, Layout};
-use std::borrow::{Borrow, BorrowMut};
-use std::mem::{ManuallyDrop, MaybeUninit};
-use std::ops::{Deref, DerefMut};
-use std::ptr;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut, RangeBounds};
+use core::ptr::{self, NonNull};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod chunks;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "serde")]
+mod serde;
+
+pub use chunks::{ChunksExact, RChunks};
+#[cfg(feature = "std")]
+pub use io::{DynArrayCursor, DynArrayReader, DynArrayWriter};
+
+/// Create a DynArray, analogous to `vec!`
+///
+/// `dyn_array![a, b, c]` builds an array of exactly the listed elements in a single allocation,
+/// `dyn_array![value; n]` builds an array of `n` clones of `value`.
+#[macro_export]
+macro_rules! dyn_array {
+    () => {
+        $crate::DynArray::default()
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::DynArray::from_elem($elem, $n)
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::DynArray::from([$($x),+])
+    };
+}
 
+/// A fixed-length, heap-allocated array
+///
+/// The layout is `#[repr(C)]` and stable: a `DynArray<T>` is exactly `{ ptr: *mut T, len: usize }`,
+/// the same two values taken by [`DynArray::from_parts`] and returned by [`DynArray::into_parts`].
 #[derive(Debug)]
+#[repr(C)]
 pub struct DynArray<T> {
     ptr: *mut T,
     len: usize,
@@ -13,12 +71,14 @@ pub struct DynArray<T> {
 impl<T> DynArray<T> {
     /// # Safety
     ///
-    /// ptr has to point to an initialized array of type T and length len
+    /// ptr has to point to an initialized array of type T and length len, allocated by the global
+    /// allocator with the layout of `[T; len]` (dangling if that layout has size zero)
     #[inline]
     pub unsafe fn from_parts(ptr: *mut T, len: usize) -> Self {
         DynArray { ptr, len }
     }
 
+    /// take the pointer and length out without dropping or freeing anything
     #[inline]
     pub fn into_parts(self) -> (*mut T, usize) {
         let me = ManuallyDrop::new(self);
@@ -29,276 +89,2244 @@ impl<T> DynArray<T> {
     #[inline]
     #[must_use]
     pub fn new_uninit(len: usize) -> DynArray<MaybeUninit<T>> {
-        let layout = Layout::array::<T>(len).unwrap();
-
-        unsafe {
-            let ptr = alloc(layout) as *mut MaybeUninit<T>;
+        let ptr = alloc_buffer::<T>(len) as *mut MaybeUninit<T>;
 
-            DynArray::<MaybeUninit<T>>::from_parts(ptr, len)
-        }
+        unsafe { DynArray::<MaybeUninit<T>>::from_parts(ptr, len) }
     }
 
-    /// allocate new DynArray of size `len` and fill with default value
+    /// move the contents into a Vec, reusing the allocation
     #[inline]
-    #[must_use]
-    pub fn new(len: usize) -> Self
-    where
-        T: Default,
-    {
-        let mut dyn_array = Self::new_uninit(len);
-
-        for elem in dyn_array.iter_mut() {
-            elem.write(T::default());
-        }
+    pub fn into_vec(self) -> Vec<T> {
+        let (ptr, len) = self.into_parts();
+        unsafe { Vec::from_raw_parts(ptr, len, len) }
+    }
 
-        dyn_array.assume_init()
+    /// consume the array and return a mutable slice living as long as needed, e.g. `'static`
+    ///
+    /// This intentionally leaks the allocation; it can only be reclaimed by passing the slice's
+    /// pointer and length back to `from_parts`.
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        let (ptr, len) = self.into_parts();
+        unsafe { core::slice::from_raw_parts_mut(ptr, len) }
     }
 
+    /// clone the contents into a new Vec, keeping the array
     #[inline]
-    pub fn len(&self) -> usize {
-        self.len
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_ref().to_vec()
     }
 
+    /// reinterpret the array as uninitialized without dropping its elements, the inverse of
+    /// `assume_init`
+    ///
+    /// The returned array does not drop its contents; elements that are still initialized have
+    /// to be read out or dropped manually, or they are leaked.
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+    pub fn into_uninit(self) -> DynArray<MaybeUninit<T>> {
+        let (ptr, len) = self.into_parts();
+        unsafe { DynArray::from_parts(ptr as *mut MaybeUninit<T>, len) }
     }
 
-    #[allow(clippy::should_implement_trait)]
-    /// Like FromIterator, but only for ExactSizeIterator
-    pub fn from_iter<I: ExactSizeIterator<Item = T>>(mut iter: I) -> Self {
-        let mut dyn_array = Self::new_uninit(iter.len());
+    /// allocate new DynArray of size `len` and fill with default value
+    #[inline]
+    #[must_use]
+    pub fn new(len: usize) -> Self
+    where
+        T: Default,
+    {
+        let mut guard = PartialInit::new(len);
 
-        for elem in dyn_array.iter_mut() {
-            elem.write(iter.next().expect("Iterator provided false size hint"));
+        while !guard.is_full() {
+            guard.push(T::default());
         }
 
-        assert!(iter.next().is_none(), "Iterator provided false size hint");
-
-        dyn_array.assume_init()
-    }
-}
-
-impl<T> Default for DynArray<T> {
-    fn default() -> Self {
-        DynArray::new_uninit(0).assume_init()
+        guard.finish()
     }
-}
 
-impl<T> DynArray<MaybeUninit<T>> {
+    /// allocate new DynArray of size `len` and fill with clones of `elem`
     #[inline]
-    pub fn assume_init(self) -> DynArray<T> {
-        let (ptr, len) = self.into_parts();
-        unsafe { DynArray::from_parts(ptr as *mut T, len) }
-    }
-}
-
-impl<T> Drop for DynArray<T> {
-    fn drop(&mut self) {
-        let ptr = self.ptr;
+    #[must_use]
+    pub fn from_elem(elem: T, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut guard = PartialInit::new(len);
 
-        for idx in 0..self.len {
-            unsafe { drop(std::ptr::read(ptr.add(idx))) };
+        while !guard.is_full() {
+            guard.push(elem.clone());
         }
 
-        let layout = Layout::array::<T>(self.len).unwrap();
-
-        unsafe {
-            dealloc(ptr as *mut u8, layout);
-        }
+        guard.finish()
     }
-}
 
-/// Clone slice into new DynArray
-impl<T: Clone> From<&[T]> for DynArray<T> {
-    fn from(slice: &[T]) -> Self {
-        let mut dyn_array = Self::new_uninit(slice.len());
+    /// allocate new DynArray of size `len`, filling the indices from `len - 1` down to 0 with
+    /// `f(&mut state, idx)`, threading `state` from the back
+    ///
+    /// If `f` panics, the elements already written are dropped.
+    pub fn new_rscan<S, F>(len: usize, init: S, mut f: F) -> Self
+    where
+        F: FnMut(&mut S, usize) -> T,
+    {
+        let mut state = init;
+        let mut guard = PartialInit::new(len);
 
-        for (dst, val) in dyn_array.iter_mut().zip(slice) {
-            dst.write(val.clone());
+        // fill back to front in reverse order, so the guard's initialized prefix holds the suffix
+        while !guard.is_full() {
+            let idx = len - 1 - guard.initialized;
+            guard.push(f(&mut state, idx));
         }
 
-        dyn_array.assume_init()
+        let mut dyn_array = guard.finish();
+        dyn_array.reverse();
+
+        dyn_array
     }
-}
 
-impl<T, const N: usize> From<[T; N]> for DynArray<T> {
-    fn from(array: [T; N]) -> Self {
-        let boxed_array: Box<[T]> = Box::new(array);
-        DynArray::from(boxed_array)
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
     }
-}
 
-impl<T> From<Box<[T]>> for DynArray<T> {
-    fn from(boxed_slice: Box<[T]>) -> Self {
-        unsafe {
-            let len = boxed_slice.len();
-            let ptr = (*Box::into_raw(boxed_slice)).as_mut_ptr();
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 
-            Self::from_parts(ptr, len)
-        }
+    /// view the whole array as a slice
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self
     }
-}
 
-impl<T: Clone> From<&mut [T]> for DynArray<T> {
-    fn from(slice: &mut [T]) -> Self {
-        DynArray::from(slice as &[T])
+    /// mutably view the whole array as a slice
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
     }
-}
 
-impl<T: Clone> Clone for DynArray<T> {
-    fn clone(&self) -> Self {
-        DynArray::from(&**self)
+    /// raw pointer to the first element
+    ///
+    /// The pointer is valid for `len` elements as long as the array is alive and not moved out
+    /// of; it must not be used to write.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
     }
-}
 
-impl<T> Deref for DynArray<T> {
-    type Target = [T];
+    /// raw mutable pointer to the first element
+    ///
+    /// The pointer is valid for `len` elements as long as the array is alive and not moved out
+    /// of.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
 
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_ref().iter()
     }
-}
 
-impl<T> DerefMut for DynArray<T> {
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut().iter_mut()
     }
-}
 
-impl<T> AsRef<[T]> for DynArray<T> {
+    /// view the whole array as a fixed-size array, if its length is exactly `N`
     #[inline]
-    fn as_ref(&self) -> &[T] {
-        self
+    pub fn as_array_ref<const N: usize>(&self) -> Option<&[T; N]> {
+        <&[T; N]>::try_from(self.as_ref()).ok()
     }
-}
 
-impl<T> AsMut<[T]> for DynArray<T> {
+    /// mutably view the whole array as a fixed-size array, if its length is exactly `N`
     #[inline]
-    fn as_mut(&mut self) -> &mut [T] {
-        self
+    pub fn as_array_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        <&mut [T; N]>::try_from(self.as_mut()).ok()
     }
-}
 
-impl<T> Borrow<[T]> for DynArray<T> {
+    /// overwrite every element with a clone of `value`, dropping the old elements
     #[inline]
-    fn borrow(&self) -> &[T] {
-        &self[..]
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.as_mut().fill(value);
     }
-}
 
-impl<T> BorrowMut<[T]> for DynArray<T> {
+    /// overwrite every element with the result of calling `f`, dropping the old elements
     #[inline]
-    fn borrow_mut(&mut self) -> &mut [T] {
-        &mut self[..]
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.as_mut().fill_with(f);
     }
-}
 
-pub struct IntoIter<T> {
-    dyn_array: DynArray<ManuallyDrop<T>>,
-    idx: usize,
-}
+    #[allow(clippy::should_implement_trait)]
+    /// Like FromIterator, but only for ExactSizeIterator
+    ///
+    /// If the iterator panics or lies about its length, the elements taken so far are dropped.
+    pub fn from_iter<I: ExactSizeIterator<Item = T>>(mut iter: I) -> Self {
+        let mut guard = PartialInit::new(iter.len());
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+        while !guard.is_full() {
+            guard.push(iter.next().expect("Iterator provided false size hint"));
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.idx >= self.dyn_array.len {
+        assert!(iter.next().is_none(), "Iterator provided false size hint");
+
+        guard.finish()
+    }
+
+    /// move the first element out, returning it together with an array of the rest
+    pub fn split_first(self) -> Option<(T, DynArray<T>)> {
+        if self.is_empty() {
             return None;
         }
 
-        self.idx += 1;
+        let rest = unsafe { Self::move_from_raw(self.ptr.add(1), self.len - 1) };
+        let (ptr, len) = self.into_parts();
 
         unsafe {
-            let ptr = self.dyn_array.ptr.add(self.idx - 1) as *mut T;
-            Some(ptr::read(ptr))
+            let first = ptr::read(ptr);
+            dealloc_buffer(ptr, len);
+
+            Some((first, rest))
         }
     }
-}
 
-impl<T> IntoIterator for DynArray<T> {
-    type Item = T;
+    /// move the last element out, returning it together with an array of the rest
+    pub fn split_last(self) -> Option<(T, DynArray<T>)> {
+        if self.is_empty() {
+            return None;
+        }
 
-    type IntoIter = IntoIter<T>;
+        let rest = unsafe { Self::move_from_raw(self.ptr, self.len - 1) };
+        let (ptr, len) = self.into_parts();
 
-    fn into_iter(self) -> Self::IntoIter {
         unsafe {
-            let (ptr, len) = self.into_parts();
-            let dyn_array = DynArray::from_parts(ptr as *mut ManuallyDrop<T>, len);
+            let last = ptr::read(ptr.add(len - 1));
+            dealloc_buffer(ptr, len);
 
-            IntoIter { dyn_array, idx: 0 }
+            Some((last, rest))
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::mem::MaybeUninit;
 
-    use super::DynArray;
+    /// combine two arrays of equal length element by element, moving the elements into `f`
+    ///
+    /// Panics if the lengths differ. If `f` panics, the outputs produced so far and the remaining
+    /// inputs of both arrays are dropped.
+    pub fn zip_with<B, C, F>(self, other: DynArray<B>, mut f: F) -> DynArray<C>
+    where
+        F: FnMut(T, B) -> C,
+    {
+        assert_eq!(self.len, other.len, "DynArray lengths differ");
 
-    #[test]
-    fn zero_len_test() {
-        let a = DynArray::<u8>::new_uninit(0).assume_init();
+        DynArray::from_iter(self.into_iter().zip(other).map(|(a, b)| f(a, b)))
+    }
 
-        assert_eq!(a.len(), 0);
+    /// combine three arrays of equal length into an array of triples, moving the elements
+    ///
+    /// Panics if the lengths differ.
+    pub fn zip3<B, C>(self, b: DynArray<B>, c: DynArray<C>) -> DynArray<(T, B, C)> {
+        assert!(
+            self.len == b.len && self.len == c.len,
+            "DynArray lengths differ"
+        );
+
+        DynArray::from_iter(self.into_iter().zip(b).zip(c).map(|((x, y), z)| (x, y, z)))
+    }
 
-        drop(a);
+    /// rotate so the element at `index` becomes the first, keeping the cyclic order
+    ///
+    /// Equivalent to `rotate_left(index)`. Panics if `index >= len`.
+    #[inline]
+    pub fn rotate_to_front(&mut self, index: usize) {
+        let len = self.len;
+        assert!(
+            index < len,
+            "rotate_to_front index (is {index}) should be < len (is {len})"
+        );
+
+        self.rotate_left(index);
     }
 
-    #[test]
-    fn uninit_test() {
-        let mut a: DynArray<MaybeUninit<u32>> = DynArray::new_uninit(20);
+    /// call `f` on every overlapping window of `size` elements, front to back
+    ///
+    /// Each window sees the changes made through the previous ones. Panics if `size` is 0.
+    pub fn for_each_window_mut<F: FnMut(&mut [T])>(&mut self, size: usize, mut f: F) {
+        assert!(size != 0, "window size must be non-zero");
 
-        for i in 0..20 {
-            a[i].write(i as u32);
+        for start in 0..self.len.saturating_sub(size - 1) {
+            f(&mut self[start..start + size]);
         }
+    }
 
-        drop(a);
+    /// collect `f` applied to every overlapping window of `size` elements
+    ///
+    /// The result has `len - size + 1` elements, or none if `size > len`. Panics if `size` is 0.
+    pub fn windows_map<U, F: FnMut(&[T]) -> U>(&self, size: usize, f: F) -> DynArray<U> {
+        assert!(size != 0, "window size must be non-zero");
+
+        DynArray::from_iter(self.windows(size).map(f))
     }
 
-    #[test]
-    fn default_test() {
-        let a: DynArray<u32> = DynArray::new(20);
+    /// clone runs of consecutive elements with equal keys into separate arrays
+    pub fn group_by_key<K, F>(&self, mut key: F) -> DynArray<DynArray<T>>
+    where
+        T: Clone,
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let groups: Vec<DynArray<T>> = self
+            .chunk_by(|a, b| key(a) == key(b))
+            .map(DynArray::from)
+            .collect();
 
-        println!("Created");
+        DynArray::from(groups)
+    }
 
-        for i in a {
-            println!("Loop");
-            assert_eq!(i, 0);
+    /// reallocate to `len + additional`, filling the new tail with clones of `value`
+    #[must_use]
+    pub fn extend_with(self, additional: usize, value: T) -> DynArray<T>
+    where
+        T: Clone,
+    {
+        let mut guard = self.grow_uninit(additional);
+
+        while !guard.is_full() {
+            guard.push(value.clone());
         }
 
-        println!("Loop done");
+        guard.finish()
     }
 
-    #[test]
-    fn test_drop() {
-        let mut x = 0;
+    /// reallocate to `len + additional`, filling the new tail with default values
+    #[must_use]
+    pub fn extend_with_default(self, additional: usize) -> DynArray<T>
+    where
+        T: Default,
+    {
+        let mut guard = self.grow_uninit(additional);
 
-        struct DropTest<'a>(&'a mut i32);
+        while !guard.is_full() {
+            guard.push(T::default());
+        }
 
-        impl Drop for DropTest<'_> {
-            fn drop(&mut self) {
-                *self.0 += 1;
-            }
+        guard.finish()
+    }
+
+    /// reallocate to `new_len`, filling the new tail with default values
+    ///
+    /// Panics if `new_len < len`; use `resize_with` to also allow shrinking. If `T::default`
+    /// panics, the elements written so far are dropped.
+    #[must_use]
+    pub fn grow(self, new_len: usize) -> DynArray<T>
+    where
+        T: Default,
+    {
+        let len = self.len;
+        assert!(
+            new_len >= len,
+            "grow length (is {new_len}) should be >= len (is {len})"
+        );
+
+        self.extend_with_default(new_len - len)
+    }
+
+    /// drop the elements from `new_len` on and reallocate down to `new_len`
+    ///
+    /// Does nothing if `new_len >= len`.
+    #[must_use]
+    pub fn shrink_to(self, new_len: usize) -> DynArray<T> {
+        if new_len >= self.len {
+            return self;
         }
 
-        let drop_test = DropTest(&mut x);
+        let (ptr, len) = self.into_parts();
 
-        drop(drop_test);
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(ptr.add(new_len), len - new_len);
+            ptr::drop_in_place(tail);
 
-        assert_eq!(x, 1);
+            DynArray::from_parts(realloc_buffer(ptr, len, new_len), new_len)
+        }
+    }
 
-        let mut drop_array = DynArray::new_uninit(1);
+    /// drop the elements from `len` on and reallocate down to exactly `len`
+    ///
+    /// Unlike `Vec::truncate`, this frees the dropped tail's memory. Does nothing if
+    /// `len >= self.len()`.
+    #[inline]
+    #[must_use]
+    pub fn truncate(self, len: usize) -> DynArray<T> {
+        self.shrink_to(len)
+    }
 
-        drop_array[0].write(DropTest(&mut x));
+    /// reallocate to exactly `new_len`, filling a new tail with clones of `value` or dropping the
+    /// elements from `new_len` on
+    #[must_use]
+    pub fn resize(self, new_len: usize, value: T) -> DynArray<T>
+    where
+        T: Clone,
+    {
+        if new_len > self.len {
+            let additional = new_len - self.len;
+            self.extend_with(additional, value)
+        } else {
+            self.shrink_to(new_len)
+        }
+    }
 
-        let drop_array = drop_array.assume_init();
+    /// reallocate to exactly `new_len`, filling a new tail by calling `f` or dropping the elements
+    /// from `new_len` on
+    ///
+    /// If `f` panics, the elements written so far are dropped.
+    #[must_use]
+    pub fn resize_with<F: FnMut() -> T>(self, new_len: usize, mut f: F) -> DynArray<T> {
+        if new_len <= self.len {
+            return self.shrink_to(new_len);
+        }
 
-        drop(drop_array);
+        let additional = new_len - self.len;
+        let mut guard = self.grow_uninit(additional);
 
-        assert_eq!(x, 2);
+        while !guard.is_full() {
+            guard.push(f());
+        }
+
+        guard.finish()
+    }
+
+    /// move out the element at `index`, filling its slot with the last element and
+    /// reallocating down to `len - 1`
+    ///
+    /// Panics if `index >= len`.
+    pub fn swap_remove(self, index: usize) -> (T, DynArray<T>) {
+        let len = self.len;
+        assert!(
+            index < len,
+            "swap_remove index (is {index}) should be < len (is {len})"
+        );
+
+        let (ptr, len) = self.into_parts();
+
+        unsafe {
+            let value = ptr::read(ptr.add(index));
+            ptr::copy(ptr.add(len - 1), ptr.add(index), 1);
+
+            let rest = DynArray::from_parts(realloc_buffer(ptr, len, len - 1), len - 1);
+
+            (value, rest)
+        }
+    }
+
+    /// insert `value` at its sorted position, reallocating to `len + 1`
+    ///
+    /// The array is assumed to be sorted; the insertion point is found by binary search, after any
+    /// elements equal to `value`. If the array isn't sorted the position is unspecified.
+    #[must_use]
+    pub fn insert_sorted(self, value: T) -> DynArray<T>
+    where
+        T: Ord,
+    {
+        // checked before taking the array apart, so that overflowing doesn't leak it
+        let new_len = self.len.checked_add(1).expect("capacity overflow");
+        Layout::array::<T>(new_len).expect("capacity overflow");
+
+        let idx = self.partition_point(|elem| elem <= &value);
+        let (ptr, len) = self.into_parts();
+
+        unsafe {
+            let ptr = realloc_buffer(ptr, len, new_len);
+
+            ptr::copy(ptr.add(idx), ptr.add(idx + 1), len - idx);
+            ptr::write(ptr.add(idx), value);
+
+            DynArray::from_parts(ptr, new_len)
+        }
+    }
+
+    /// keep only the elements for which `pred` returns true, moving them into a new exact-size
+    /// array and dropping the rest
+    ///
+    /// `pred` may mutate the elements while deciding.
+    #[must_use]
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(self, mut pred: F) -> DynArray<T> {
+        let mut kept = Vec::with_capacity(self.len);
+
+        for mut elem in self {
+            if pred(&mut elem) {
+                kept.push(elem);
+            }
+        }
+
+        DynArray::from(kept)
+    }
+
+    /// pair every element with its index, moving the elements
+    #[inline]
+    pub fn enumerate(self) -> DynArray<(usize, T)> {
+        DynArray::from_iter(self.into_iter().enumerate())
+    }
+
+    /// move the elements for which `pred` returns true into the first array and the rest into
+    /// the second, keeping their relative order
+    pub fn partition<F: FnMut(&T) -> bool>(self, mut pred: F) -> (DynArray<T>, DynArray<T>) {
+        let mut left = Vec::with_capacity(self.len);
+        let mut right = Vec::with_capacity(self.len);
+
+        for elem in self {
+            if pred(&elem) {
+                left.push(elem);
+            } else {
+                right.push(elem);
+            }
+        }
+
+        (DynArray::from(left), DynArray::from(right))
+    }
+
+    /// map every element through `f`, keeping only the `Some` results
+    pub fn filter_map<U, F: FnMut(T) -> Option<U>>(self, f: F) -> DynArray<U> {
+        let mut kept = Vec::with_capacity(self.len);
+        kept.extend(self.into_iter().filter_map(f));
+
+        DynArray::from(kept)
+    }
+
+    /// reallocate to `len + additional`, with the existing elements as the initialized prefix
+    fn grow_uninit(self, additional: usize) -> PartialInit<T> {
+        // checked before taking the array apart, so that overflowing doesn't leak it
+        let new_len = self.len.checked_add(additional).expect("capacity overflow");
+        Layout::array::<T>(new_len).expect("capacity overflow");
+
+        let (ptr, len) = self.into_parts();
+
+        unsafe {
+            let new_ptr = realloc_buffer(ptr, len, new_len);
+
+            PartialInit {
+                dyn_array: DynArray::from_parts(new_ptr as *mut MaybeUninit<T>, new_len),
+                initialized: len,
+            }
+        }
+    }
+
+    /// clone the elements for which `pred` returns true into a new array, keeping the original
+    pub fn retain<F: FnMut(&T) -> bool>(&self, mut pred: F) -> DynArray<T>
+    where
+        T: Clone,
+    {
+        let kept: Vec<&T> = self.iter().filter(|elem| pred(elem)).collect();
+
+        DynArray::from_iter(kept.into_iter().cloned())
+    }
+
+    /// clone into a new array, collapsing runs of consecutive elements that `same` considers equal
+    /// into their first element
+    ///
+    /// Like `Vec::dedup_by`, `same` is called with the current element and the last one kept.
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&self, mut same: F) -> DynArray<T>
+    where
+        T: Clone,
+    {
+        let mut kept: Vec<&T> = Vec::with_capacity(self.len);
+
+        for elem in self.iter() {
+            if kept.last().is_none_or(|last| !same(elem, last)) {
+                kept.push(elem);
+            }
+        }
+
+        DynArray::from_iter(kept.into_iter().cloned())
+    }
+
+    /// clone into a new array, collapsing runs of consecutive equal elements into their first
+    /// element
+    #[inline]
+    pub fn dedup(&self) -> DynArray<T>
+    where
+        T: PartialEq + Clone,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// split into `n` contiguous parts of as equal length as possible, moving the elements
+    ///
+    /// Earlier parts get the extra elements if `n` doesn't divide the length; if `n > len`, the
+    /// trailing parts are empty. Panics if `n` is 0.
+    pub fn split_into(self, n: usize) -> Vec<DynArray<T>> {
+        assert!(n != 0, "number of parts must be non-zero");
+
+        let (base, extra) = (self.len / n, self.len % n);
+
+        // allocated before taking the array apart, so that a huge `n` doesn't leak it
+        let mut parts = Vec::with_capacity(n);
+        let (ptr, len) = self.into_parts();
+        let mut start = 0;
+
+        for i in 0..n {
+            let part_len = if i < extra { base + 1 } else { base };
+            parts.push(unsafe { Self::move_from_raw(ptr.add(start), part_len) });
+            start += part_len;
+        }
+
+        unsafe { dealloc_buffer(ptr, len) };
+
+        parts
+    }
+
+    /// transpose a row-major matrix of `len / width` rows and `width` columns, returning the
+    /// elements in column-major order
+    ///
+    /// Panics if `width` is 0 or doesn't divide the length.
+    pub fn transpose_flat(&self, width: usize) -> DynArray<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            width != 0 && self.len.is_multiple_of(width),
+            "width must be non-zero and divide the length"
+        );
+
+        let rows = self.len / width;
+
+        DynArray::from_iter(
+            (0..self.len).map(|idx| self[(idx % rows) * width + idx / rows].clone()),
+        )
+    }
+
+    /// # Safety
+    ///
+    /// src has to point to `len` initialized elements, which are moved (bitwise copied) into the
+    /// new array; the caller must make sure they are not used or dropped through `src` afterwards
+    unsafe fn move_from_raw(src: *const T, len: usize) -> Self {
+        let dyn_array = Self::new_uninit(len);
+        ptr::copy_nonoverlapping(src, dyn_array.ptr as *mut T, len);
+
+        dyn_array.assume_init()
+    }
+}
+
+impl<T> Default for DynArray<T> {
+    fn default() -> Self {
+        unsafe { DynArray::new_uninit(0).assume_init() }
+    }
+}
+
+impl<T> DynArray<MaybeUninit<T>> {
+    /// convert to `DynArray<T>`, without touching the elements
+    ///
+    /// # Safety
+    ///
+    /// every element has to be initialized, as with `MaybeUninit::assume_init`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynarray::DynArray;
+    ///
+    /// let mut a = DynArray::<u32>::new_uninit(3);
+    ///
+    /// for (i, slot) in a.iter_mut().enumerate() {
+    ///     slot.write(i as u32);
+    /// }
+    ///
+    /// // every slot has been written above
+    /// let a = unsafe { a.assume_init() };
+    /// assert_eq!(&*a, &[0, 1, 2]);
+    /// ```
+    #[inline]
+    pub unsafe fn assume_init(self) -> DynArray<T> {
+        let (ptr, len) = self.into_parts();
+        unsafe { DynArray::from_parts(ptr as *mut T, len) }
+    }
+
+    /// view the whole array as initialized, without consuming it
+    ///
+    /// # Safety
+    ///
+    /// every element has to be initialized; as with `MaybeUninit::assume_init_ref`, calling this
+    /// when the contents are not yet fully initialized causes undefined behavior
+    #[inline]
+    pub unsafe fn assume_init_ref(&self) -> &[T] {
+        core::slice::from_raw_parts(self.ptr as *const T, self.len)
+    }
+
+    /// mutably view the whole array as initialized, without consuming it
+    ///
+    /// # Safety
+    ///
+    /// every element has to be initialized; as with `MaybeUninit::assume_init_mut`, calling this
+    /// when the contents are not yet fully initialized causes undefined behavior
+    #[inline]
+    pub unsafe fn assume_init_mut(&mut self) -> &mut [T] {
+        core::slice::from_raw_parts_mut(self.ptr as *mut T, self.len)
+    }
+
+    /// drop every element in place, leaving the array uninitialized
+    ///
+    /// # Safety
+    ///
+    /// every element has to be initialized, as with `MaybeUninit::assume_init_drop`; afterwards
+    /// the elements must be treated as uninitialized
+    pub unsafe fn assume_init_drop(&mut self) {
+        ptr::drop_in_place(self.assume_init_mut());
+    }
+
+    /// view the elements in `range` as initialized
+    ///
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// every element in `range` has to be initialized
+    #[inline]
+    pub unsafe fn assume_init_slice<R: RangeBounds<usize>>(&self, range: R) -> &[T] {
+        let slice = &self[(range.start_bound().cloned(), range.end_bound().cloned())];
+        core::slice::from_raw_parts(slice.as_ptr() as *const T, slice.len())
+    }
+
+    /// mutably view the elements in `range` as initialized
+    ///
+    /// Panics if `range` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// every element in `range` has to be initialized
+    #[inline]
+    pub unsafe fn assume_init_slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> &mut [T] {
+        let slice = &mut self[(range.start_bound().cloned(), range.end_bound().cloned())];
+        core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, slice.len())
+    }
+
+    /// copy `src` into the array, initializing every element, and return the initialized view
+    ///
+    /// Panics if `src` and the array differ in length.
+    pub fn write_copy_of_slice(&mut self, src: &[T]) -> &mut [T]
+    where
+        T: Copy,
+    {
+        assert_eq!(self.len, src.len(), "DynArray lengths differ");
+
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), self.ptr as *mut T, self.len);
+            self.assume_init_mut()
+        }
+    }
+
+    /// clone `src` into the array, initializing every element, and return the initialized view
+    ///
+    /// Panics if `src` and the array differ in length. If a `clone` panics, the clones written
+    /// so far are dropped.
+    pub fn write_clone_of_slice(&mut self, src: &[T]) -> &mut [T]
+    where
+        T: Clone,
+    {
+        assert_eq!(self.len, src.len(), "DynArray lengths differ");
+
+        let mut guard = PrefixGuard {
+            ptr: self.ptr as *mut T,
+            initialized: 0,
+        };
+
+        for (slot, elem) in self.iter_mut().zip(src) {
+            slot.write(elem.clone());
+            guard.initialized += 1;
+        }
+
+        core::mem::forget(guard);
+
+        unsafe { self.assume_init_mut() }
+    }
+}
+
+impl<A, B, C> DynArray<(A, B, C)> {
+    /// split an array of triples into three arrays of equal length, moving the elements
+    pub fn unzip3(self) -> (DynArray<A>, DynArray<B>, DynArray<C>) {
+        let mut a = PartialInit::new(self.len);
+        let mut b = PartialInit::new(self.len);
+        let mut c = PartialInit::new(self.len);
+
+        for (x, y, z) in self {
+            a.push(x);
+            b.push(y);
+            c.push(z);
+        }
+
+        (a.finish(), b.finish(), c.finish())
+    }
+}
+
+impl<T> DynArray<Option<T>> {
+    /// move the inner values out if every element is `Some`
+    ///
+    /// Returns `None` at the first `None` element, dropping all other elements.
+    pub fn transpose(self) -> Option<DynArray<T>> {
+        let mut guard = PartialInit::new(self.len);
+
+        for elem in self {
+            guard.push(elem?);
+        }
+
+        Some(guard.finish())
+    }
+}
+
+impl<T, E> DynArray<Result<T, E>> {
+    /// move the `Ok` values out if every element is `Ok`
+    ///
+    /// Returns the first `Err`, dropping all other elements.
+    pub fn collect_result(self) -> Result<DynArray<T>, E> {
+        let mut guard = PartialInit::new(self.len);
+
+        for elem in self {
+            guard.push(elem?);
+        }
+
+        Ok(guard.finish())
+    }
+}
+
+impl<T> Drop for DynArray<T> {
+    fn drop(&mut self) {
+        let ptr = self.ptr;
+
+        for idx in 0..self.len {
+            unsafe { drop(ptr::read(ptr.add(idx))) };
+        }
+
+        unsafe { dealloc_buffer(ptr, self.len) };
+    }
+}
+
+/// Fills a `DynArray<MaybeUninit<T>>` front to back and drops the initialized prefix if
+/// filling is abandoned before the array is full
+///
+/// Every constructor that fills slots one by one goes through this, so that a panicking or
+/// failing element constructor never leaks the elements written before it.
+///
+/// # Examples
+///
+/// ```
+/// use dynarray::PartialInit;
+///
+/// let mut builder = PartialInit::new(3);
+///
+/// for s in ["1", "2", "3"] {
+///     builder.push(s.parse::<u32>().unwrap());
+/// }
+///
+/// assert_eq!(builder.len(), builder.capacity());
+/// assert_eq!(&*builder.finish(), &[1, 2, 3]);
+/// ```
+pub struct PartialInit<T> {
+    dyn_array: DynArray<MaybeUninit<T>>,
+    initialized: usize,
+}
+
+impl<T> PartialInit<T> {
+    /// allocate room for `len` elements, none of them initialized yet
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        PartialInit {
+            dyn_array: DynArray::new_uninit(len),
+            initialized: 0,
+        }
+    }
+
+    /// number of slots written so far
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.initialized
+    }
+
+    /// true if no slot has been written yet
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.initialized == 0
+    }
+
+    /// length of the finished array
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.dyn_array.len
+    }
+
+    /// true if every slot has been written
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.initialized == self.dyn_array.len
+    }
+
+    /// write the next uninitialized slot, panics if already full
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.dyn_array[self.initialized].write(value);
+        self.initialized += 1;
+    }
+
+    /// panics if not every slot has been written
+    pub fn finish(mut self) -> DynArray<T> {
+        assert!(self.is_full(), "DynArray not fully initialized");
+
+        let dyn_array = core::mem::take(&mut self.dyn_array);
+        self.initialized = 0;
+
+        unsafe { dyn_array.assume_init() }
+    }
+}
+
+impl<T> Drop for PartialInit<T> {
+    fn drop(&mut self) {
+        drop(PrefixGuard {
+            ptr: self.dyn_array.ptr as *mut T,
+            initialized: self.initialized,
+        });
+    }
+}
+
+/// Drops the first `initialized` elements behind a borrowed `ptr`, unless forgotten
+///
+/// The buffer itself is not freed, that stays with its owner.
+struct PrefixGuard<T> {
+    ptr: *mut T,
+    initialized: usize,
+}
+
+impl<T> Drop for PrefixGuard<T> {
+    fn drop(&mut self) {
+        let initialized = ptr::slice_from_raw_parts_mut(self.ptr, self.initialized);
+        unsafe { ptr::drop_in_place(initialized) };
+    }
+}
+
+/// most memory to allocate up front for a length that comes from untrusted input, the same cap
+/// serde uses for `Vec`
+#[cfg(any(feature = "borsh", feature = "serde"))]
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+/// true if `len` elements fit within `MAX_PREALLOC_BYTES`, so that a length taken from the input
+/// can be allocated at once without letting the input exhaust memory
+#[cfg(any(feature = "borsh", feature = "serde"))]
+pub(crate) fn is_cautious_len<T>(len: usize) -> bool {
+    core::mem::size_of::<T>().saturating_mul(len) <= MAX_PREALLOC_BYTES
+}
+
+/// allocate a buffer for `len` elements, using a dangling pointer if no memory is needed
+///
+/// The layout matches what `Vec` and `Box<[T]>` use, so buffers can be passed between them.
+fn alloc_buffer<T>(len: usize) -> *mut T {
+    alloc_buffer_with(len, alloc)
+}
+
+/// like `alloc_buffer`, with every byte of the buffer set to zero
+#[cfg(feature = "bytemuck")]
+pub(crate) fn alloc_buffer_zeroed<T>(len: usize) -> *mut T {
+    alloc_buffer_with(len, alloc::alloc::alloc_zeroed)
+}
+
+fn alloc_buffer_with<T>(len: usize, allocate: unsafe fn(Layout) -> *mut u8) -> *mut T {
+    let layout = Layout::array::<T>(len).unwrap();
+
+    if layout.size() == 0 {
+        return NonNull::dangling().as_ptr();
+    }
+
+    let ptr = unsafe { allocate(layout) as *mut T };
+
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+
+    ptr
+}
+
+/// # Safety
+///
+/// ptr has to come from `alloc_buffer::<T>(len)` (or an equivalent `Vec`/`Box<[T]>` allocation)
+unsafe fn dealloc_buffer<T>(ptr: *mut T, len: usize) {
+    let layout = Layout::array::<T>(len).unwrap();
+
+    if layout.size() != 0 {
+        dealloc(ptr as *mut u8, layout);
+    }
+}
+
+/// move the buffer to an allocation for `new_len` elements, keeping the first
+/// `min(old_len, new_len)` elements
+///
+/// # Safety
+///
+/// ptr has to come from `alloc_buffer::<T>(old_len)`, and elements past `new_len` have to be
+/// dropped already
+unsafe fn realloc_buffer<T>(ptr: *mut T, old_len: usize, new_len: usize) -> *mut T {
+    let old_layout = Layout::array::<T>(old_len).unwrap();
+    let new_layout = Layout::array::<T>(new_len).unwrap();
+
+    if old_layout.size() == 0 {
+        return alloc_buffer(new_len);
+    }
+
+    if new_layout.size() == 0 {
+        dealloc_buffer(ptr, old_len);
+        return NonNull::dangling().as_ptr();
+    }
+
+    let new_ptr = realloc(ptr as *mut u8, old_layout, new_layout.size()) as *mut T;
+
+    if new_ptr.is_null() {
+        handle_alloc_error(new_layout);
+    }
+
+    new_ptr
+}
+
+/// Clone slice into new DynArray
+impl<T: Clone> From<&[T]> for DynArray<T> {
+    fn from(slice: &[T]) -> Self {
+        DynArray::from_iter(slice.iter().cloned())
+    }
+}
+
+/// Moves the elements into a single exact-size allocation
+impl<T, const N: usize> From<[T; N]> for DynArray<T> {
+    fn from(array: [T; N]) -> Self {
+        let array = ManuallyDrop::new(array);
+        let dyn_array = Self::new_uninit(N);
+
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), dyn_array.ptr as *mut T, N);
+            dyn_array.assume_init()
+        }
+    }
+}
+
+impl<T> From<Box<[T]>> for DynArray<T> {
+    fn from(boxed_slice: Box<[T]>) -> Self {
+        unsafe {
+            let len = boxed_slice.len();
+            let ptr = (*Box::into_raw(boxed_slice)).as_mut_ptr();
+
+            Self::from_parts(ptr, len)
+        }
+    }
+}
+
+/// Shrinks the Vec to fit, then takes over its allocation
+impl<T> From<Vec<T>> for DynArray<T> {
+    fn from(vec: Vec<T>) -> Self {
+        DynArray::from(vec.into_boxed_slice())
+    }
+}
+
+impl<T> From<DynArray<T>> for Vec<T> {
+    fn from(dyn_array: DynArray<T>) -> Self {
+        dyn_array.into_vec()
+    }
+}
+
+/// Reinterprets a flat array as `len / N` arrays of `N` elements, without copying
+///
+/// Fails and hands back the original if `N` is 0 or doesn't divide the length.
+impl<T, const N: usize> TryFrom<DynArray<T>> for DynArray<[T; N]> {
+    type Error = DynArray<T>;
+
+    fn try_from(dyn_array: DynArray<T>) -> Result<Self, Self::Error> {
+        if N == 0 || !dyn_array.len.is_multiple_of(N) {
+            return Err(dyn_array);
+        }
+
+        let (ptr, len) = dyn_array.into_parts();
+        Ok(unsafe { DynArray::from_parts(ptr as *mut [T; N], len / N) })
+    }
+}
+
+impl<T: Clone> From<&mut [T]> for DynArray<T> {
+    fn from(slice: &mut [T]) -> Self {
+        DynArray::from(slice as &[T])
+    }
+}
+
+impl<T: Clone> Clone for DynArray<T> {
+    fn clone(&self) -> Self {
+        DynArray::from(&**self)
+    }
+}
+
+impl<T> Deref for DynArray<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T> DerefMut for DynArray<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<T> AsRef<[T]> for DynArray<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for DynArray<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Borrow<[T]> for DynArray<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        &self[..]
+    }
+}
+
+impl<T> BorrowMut<[T]> for DynArray<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut self[..]
+    }
+}
+
+pub struct IntoIter<T> {
+    dyn_array: DynArray<ManuallyDrop<T>>,
+    idx: usize,
+}
+
+impl<T> IntoIter<T> {
+    /// move the elements that haven't been yielded yet into a new exact-size array
+    pub fn collect_remaining(mut self) -> DynArray<T> {
+        let remaining = unsafe {
+            let src = self.dyn_array.ptr.add(self.idx) as *const T;
+            DynArray::move_from_raw(src, self.dyn_array.len - self.idx)
+        };
+
+        self.idx = self.dyn_array.len;
+
+        remaining
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.dyn_array.len {
+            return None;
+        }
+
+        self.idx += 1;
+
+        unsafe {
+            let ptr = self.dyn_array.ptr.add(self.idx - 1) as *mut T;
+            Some(ptr::read(ptr))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.dyn_array.len - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// Drops the elements that haven't been yielded
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = self.dyn_array.ptr.add(self.idx) as *mut T;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                remaining,
+                self.dyn_array.len - self.idx,
+            ));
+        }
+    }
+}
+
+impl<T> IntoIterator for DynArray<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe {
+            let (ptr, len) = self.into_parts();
+            let dyn_array = DynArray::from_parts(ptr as *mut ManuallyDrop<T>, len);
+
+            IntoIter { dyn_array, idx: 0 }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::mem::{size_of, MaybeUninit};
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    use super::{DynArray, PartialInit};
+
+    #[test]
+    fn layout_test() {
+        const _: () =
+            assert!(size_of::<DynArray<u8>>() == size_of::<*mut u8>() + size_of::<usize>());
+
+        assert_eq!(
+            size_of::<DynArray<u8>>(),
+            size_of::<*mut u8>() + size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn zero_len_test() {
+        let a = unsafe { DynArray::<u8>::new_uninit(0).assume_init() };
+
+        assert_eq!(a.len(), 0);
+
+        drop(a);
+    }
+
+    #[test]
+    fn uninit_test() {
+        let mut a: DynArray<MaybeUninit<u32>> = DynArray::new_uninit(20);
+
+        for i in 0..20 {
+            a[i].write(i as u32);
+        }
+
+        drop(a);
+    }
+
+    #[test]
+    fn assume_init_ref_test() {
+        let mut a: DynArray<MaybeUninit<u32>> = DynArray::new_uninit(4);
+
+        for (i, slot) in a.iter_mut().enumerate() {
+            slot.write(i as u32 * 2);
+        }
+
+        unsafe {
+            assert_eq!(a.assume_init_ref(), &[0, 2, 4, 6]);
+
+            a.assume_init_mut()[3] = 7;
+            assert_eq!(a.assume_init_ref(), &[0, 2, 4, 7]);
+        }
+    }
+
+    #[test]
+    fn write_copy_of_slice_test() {
+        let mut a: DynArray<MaybeUninit<u16>> = DynArray::new_uninit(3);
+
+        let init = a.write_copy_of_slice(&[4, 5, 6]);
+        init[0] = 1;
+        assert_eq!(init, &[1, 5, 6]);
+
+        let a = unsafe { a.assume_init() };
+        assert_eq!(&*a, &[1, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "DynArray lengths differ")]
+    fn write_copy_of_slice_length_mismatch_test() {
+        let mut a: DynArray<MaybeUninit<u16>> = DynArray::new_uninit(3);
+        a.write_copy_of_slice(&[1, 2]);
+    }
+
+    #[test]
+    fn write_clone_of_slice_test() {
+        let rc = Rc::new(());
+        let src = DynArray::from_elem(rc.clone(), 3);
+
+        let mut a: DynArray<MaybeUninit<Rc<()>>> = DynArray::new_uninit(3);
+        assert_eq!(a.write_clone_of_slice(&src).len(), 3);
+        assert_eq!(Rc::strong_count(&rc), 7);
+
+        drop(unsafe { a.assume_init() });
+        assert_eq!(Rc::strong_count(&rc), 4);
+    }
+
+    #[test]
+    fn as_slice_test() {
+        let mut a = DynArray::from([1, 2, 3]);
+
+        a.as_mut_slice()[1] = 5;
+        assert_eq!(a.as_slice(), &[1, 5, 3]);
+    }
+
+    #[test]
+    fn as_ptr_test() {
+        let mut a = DynArray::from([1, 2, 3]);
+
+        let ptr = a.as_ptr();
+        let mut_ptr = a.as_mut_ptr();
+        unsafe { *mut_ptr.add(2) = 4 };
+
+        let (raw, len) = a.into_parts();
+        assert_eq!(ptr, raw as *const i32);
+        assert_eq!(mut_ptr, raw);
+
+        let a = unsafe { DynArray::from_parts(raw, len) };
+        assert_eq!(&*a, &[1, 2, 4]);
+    }
+
+    #[test]
+    fn leak_test() {
+        let leaked: &'static mut [String] = DynArray::from_elem(String::from("a"), 3).leak();
+        leaked[1].push('b');
+        assert_eq!(leaked, &["a", "ab", "a"]);
+
+        let a = unsafe { DynArray::from_parts(leaked.as_mut_ptr(), leaked.len()) };
+        assert_eq!(&*a, &["a", "ab", "a"]);
+    }
+
+    #[test]
+    fn fill_test() {
+        let count = Cell::new(0);
+        let mut a = DynArray::from_iter((0..4).map(|_| Rc::new(DropCounter(&count))));
+
+        let value = Rc::new(DropCounter(&count));
+        a.fill(value.clone());
+
+        assert_eq!(count.get(), 4);
+        assert!(a.iter().all(|x| Rc::ptr_eq(x, &value)));
+    }
+
+    #[test]
+    fn fill_with_test() {
+        let mut a = DynArray::from([0; 5]);
+
+        let mut calls = 0;
+        a.fill_with(|| {
+            calls += 1;
+            calls * 10
+        });
+
+        assert_eq!(calls, 5);
+        assert_eq!(&*a, &[10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn to_vec_test() {
+        let a = DynArray::from([String::from("a"), String::from("b")]);
+
+        let mut v = a.to_vec();
+        v.push(String::from("c"));
+
+        assert_eq!(v, ["a", "b", "c"]);
+        assert_eq!(&*a, &["a", "b"]);
+    }
+
+    #[test]
+    fn into_uninit_test() {
+        let a = DynArray::from([String::from("a"), String::from("b")]);
+
+        let mut a = a.into_uninit();
+        let b = unsafe { a[1].assume_init_read() };
+        a[1].write(String::from("c"));
+
+        let a = unsafe { a.assume_init() };
+        assert_eq!(&*a, &["a", "c"]);
+        assert_eq!(b, "b");
+    }
+
+    #[test]
+    fn assume_init_drop_test() {
+        let count = Cell::new(0);
+        let mut a = DynArray::from_iter((0..5).map(|_| DropCounter(&count))).into_uninit();
+
+        unsafe { a.assume_init_drop() };
+        assert_eq!(count.get(), 5);
+
+        drop(a);
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn partial_init_test() {
+        let count = Cell::new(0);
+
+        let mut builder = PartialInit::new(4);
+        assert!(builder.is_empty());
+
+        for _ in 0..4 {
+            builder.push(DropCounter(&count));
+        }
+
+        assert_eq!(builder.len(), 4);
+        assert_eq!(builder.finish().len(), 4);
+        assert_eq!(count.get(), 4);
+
+        let mut builder = PartialInit::new(4);
+        builder.push(DropCounter(&count));
+        builder.push(DropCounter(&count));
+        assert_eq!(builder.capacity(), 4);
+
+        drop(builder);
+        assert_eq!(count.get(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "DynArray not fully initialized")]
+    fn partial_init_finish_test() {
+        let mut builder = PartialInit::new(2);
+        builder.push(1);
+        builder.finish();
+    }
+
+    #[test]
+    fn assume_init_slice_test() {
+        let mut a: DynArray<MaybeUninit<String>> = DynArray::new_uninit(5);
+
+        for (i, slot) in a[..3].iter_mut().enumerate() {
+            slot.write(i.to_string());
+        }
+
+        unsafe {
+            assert_eq!(a.assume_init_slice(..3), &["0", "1", "2"]);
+            assert_eq!(a.assume_init_slice(1..=2), &["1", "2"]);
+
+            a.assume_init_slice_mut(2..3)[0].push('!');
+            assert_eq!(a.assume_init_slice(2..3), &["2!"]);
+
+            for slot in &mut a[..3] {
+                slot.assume_init_drop();
+            }
+        }
+    }
+
+    #[test]
+    fn default_test() {
+        let a: DynArray<u32> = DynArray::new(20);
+
+        println!("Created");
+
+        for i in a {
+            println!("Loop");
+            assert_eq!(i, 0);
+        }
+
+        println!("Loop done");
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut a = DynArray::from([1, 2, 3]);
+
+        for x in a.iter_mut() {
+            *x *= 10;
+        }
+
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+    }
+
+    #[test]
+    fn as_array_ref_test() {
+        let a = DynArray::from([1, 2, 3]);
+
+        assert_eq!(a.as_array_ref(), Some(&[1, 2, 3]));
+        assert!(a.as_array_ref::<2>().is_none());
+        assert!(a.as_array_ref::<4>().is_none());
+
+        let empty: DynArray<i32> = DynArray::default();
+        assert_eq!(empty.as_array_ref(), Some(&[]));
+    }
+
+    #[test]
+    fn as_array_mut_test() {
+        let mut a = DynArray::from([1, 2, 3]);
+
+        let array: &mut [i32; 3] = a.as_array_mut().unwrap();
+        array.swap(0, 2);
+        array[1] = 20;
+
+        assert_eq!(&*a, &[3, 20, 1]);
+        assert!(a.as_array_mut::<2>().is_none());
+        assert!(a.as_array_mut::<4>().is_none());
+    }
+
+    #[test]
+    fn split_first_test() {
+        let mut a = DynArray::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+        let mut drained = Vec::new();
+
+        while let Some((first, rest)) = a.split_first() {
+            drained.push(first);
+            a = rest;
+
+            assert_eq!(a.len(), 3 - drained.len());
+        }
+
+        assert_eq!(drained, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn split_last_test() {
+        let a = DynArray::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+
+        let (last, rest) = a.split_last().unwrap();
+        assert_eq!(last, "c");
+        assert_eq!(&*rest, &["a", "b"]);
+
+        assert!(DynArray::<String>::default().split_last().is_none());
+    }
+
+    #[test]
+    fn for_each_window_mut_test() {
+        let mut a = DynArray::from([0, 9, 0, 27, 0]);
+        let mut calls = 0;
+
+        a.for_each_window_mut(3, |window| {
+            window[1] = (window[0] + window[1] + window[2]) / 3;
+            calls += 1;
+        });
+
+        assert_eq!(calls, 3);
+        assert_eq!(&*a, &[0, 3, 10, 12, 0]);
+
+        a.for_each_window_mut(6, |_| panic!("window larger than array"));
+    }
+
+    #[test]
+    fn zip_with_test() {
+        let a = DynArray::from([1, 2, 3]);
+        let b = DynArray::from([10, 20, 30]);
+
+        assert_eq!(&*a.zip_with(b, |x, y| x + y), &[11, 22, 33]);
+    }
+
+    #[test]
+    fn zip_with_panic_test() {
+        let count = Cell::new(0);
+
+        let a = DynArray::from_iter((0..5).map(|_| DropCounter(&count)));
+        let b = DynArray::from_iter((0..5).map(|_| DropCounter(&count)));
+        let mut calls = 0;
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            a.zip_with(b, |x, y| {
+                calls += 1;
+                assert!(calls != 3, "zip_with panicked");
+                (x, y)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(count.get(), 10);
+    }
+
+    #[test]
+    fn unzip3_test() {
+        let a = DynArray::from([(1, "a", 'x'), (2, "b", 'y'), (3, "c", 'z')]);
+        let (nums, strs, chars) = a.unzip3();
+
+        assert_eq!(&*nums, &[1, 2, 3]);
+        assert_eq!(&*strs, &["a", "b", "c"]);
+        assert_eq!(&*chars, &['x', 'y', 'z']);
+    }
+
+    #[test]
+    fn transpose_test() {
+        let a = DynArray::from([Some(1), Some(2), Some(3)]).transpose();
+        assert_eq!(&*a.unwrap(), &[1, 2, 3]);
+
+        let count = Cell::new(0);
+        let b = DynArray::from_iter((0..5).map(|i| (i != 2).then(|| DropCounter(&count))));
+        assert!(b.transpose().is_none());
+        assert_eq!(count.get(), 4);
+    }
+
+    #[test]
+    fn collect_result_test() {
+        let a = DynArray::from([Ok::<_, ()>(1), Ok(2), Ok(3)]).collect_result();
+        assert_eq!(&*a.unwrap(), &[1, 2, 3]);
+
+        let count = Cell::new(0);
+        let b = DynArray::from_iter((0..5).map(|i| match i {
+            2 => Err(i),
+            _ => Ok(DropCounter(&count)),
+        }));
+
+        assert!(matches!(b.collect_result(), Err(2)));
+        assert_eq!(count.get(), 4);
+    }
+
+    #[test]
+    fn zip3_test() {
+        let a = DynArray::from([1, 2, 3]);
+        let b = DynArray::from(["a", "b", "c"]);
+        let c = DynArray::from(['x', 'y', 'z']);
+
+        let zipped = a.clone().zip3(b.clone(), c.clone());
+        assert_eq!(&*zipped, &[(1, "a", 'x'), (2, "b", 'y'), (3, "c", 'z')]);
+
+        let (a2, b2, c2) = zipped.unzip3();
+        assert_eq!(&*a2, &*a);
+        assert_eq!(&*b2, &*b);
+        assert_eq!(&*c2, &*c);
+    }
+
+    #[test]
+    #[should_panic(expected = "DynArray lengths differ")]
+    fn zip3_mismatch_test() {
+        let _ = DynArray::from([1, 2]).zip3(DynArray::from([1, 2]), DynArray::from([1]));
+    }
+
+    #[test]
+    fn collect_remaining_test() {
+        let count = Cell::new(0);
+
+        let mut iter = DynArray::from_iter((0..5).map(|i| (i, DropCounter(&count)))).into_iter();
+        drop(iter.next());
+        drop(iter.next());
+
+        let rest = iter.collect_remaining();
+        assert_eq!(count.get(), 2);
+        assert_eq!(rest.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [2, 3, 4]);
+
+        drop(rest);
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn into_iter_drop_test() {
+        let count = Cell::new(0);
+
+        let mut iter = DynArray::from_iter((0..5).map(|_| DropCounter(&count))).into_iter();
+        assert_eq!(iter.len(), 5);
+
+        drop(iter.next());
+        assert_eq!(iter.len(), 4);
+
+        drop(iter);
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn extend_with_test() {
+        let a = DynArray::from([1, 2]).extend_with(2, 0);
+        assert_eq!(&*a, &[1, 2, 0, 0]);
+
+        let b = DynArray::from(["a".to_string()]).extend_with_default(2);
+        assert_eq!(&*b, &["a", "", ""]);
+
+        let c = DynArray::default().extend_with(3, 'x');
+        assert_eq!(&*c, &['x'; 3]);
+    }
+
+    #[test]
+    fn grow_test() {
+        let a = DynArray::from([1, 2]).grow(5);
+        assert_eq!(&*a, &[1, 2, 0, 0, 0]);
+
+        let a = a.grow(5);
+        assert_eq!(&*a, &[1, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grow length (is 1) should be >= len (is 2)")]
+    fn grow_shorter_test() {
+        let _ = DynArray::from([1, 2]).grow(1);
+    }
+
+    #[test]
+    fn extend_with_overflow_test() {
+        let rc = Rc::new(());
+
+        for additional in [usize::MAX, usize::MAX / size_of::<Rc<()>>()] {
+            let a = DynArray::from_elem(rc.clone(), 2);
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| a.extend_with(additional, rc.clone())));
+
+            assert!(result.is_err());
+            assert_eq!(Rc::strong_count(&rc), 1);
+        }
+    }
+
+    #[test]
+    fn shrink_to_test() {
+        let count = Cell::new(0);
+
+        let a = DynArray::from_iter((0..5).map(|_| DropCounter(&count)));
+
+        let a = a.shrink_to(2);
+        assert_eq!(a.len(), 2);
+        assert_eq!(count.get(), 3);
+
+        let a = a.shrink_to(4);
+        assert_eq!(a.len(), 2);
+        assert_eq!(count.get(), 3);
+
+        drop(a.shrink_to(0));
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn truncate_test() {
+        let count = Cell::new(0);
+
+        let a = DynArray::from_iter((1..5).map(|i| (i, DropCounter(&count))));
+
+        let a = a.truncate(2);
+        assert_eq!(count.get(), 2);
+        assert_eq!(a.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [1, 2]);
+
+        let a = a.truncate(3);
+        assert_eq!(a.len(), 2);
+        assert_eq!(count.get(), 2);
+
+        drop(a);
+        assert_eq!(count.get(), 4);
+    }
+
+    #[test]
+    fn resize_test() {
+        let a = DynArray::from([1, 2]).resize(4, 7);
+        assert_eq!(&*a, &[1, 2, 7, 7]);
+
+        let a = a.resize(4, 0);
+        assert_eq!(&*a, &[1, 2, 7, 7]);
+
+        let rc = Rc::new(());
+        let b = DynArray::from_elem(rc.clone(), 4);
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        let b = b.resize(1, rc.clone());
+        assert_eq!(b.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+
+        let b = b.resize(3, rc.clone());
+        assert_eq!(b.len(), 3);
+        assert_eq!(Rc::strong_count(&rc), 4);
+    }
+
+    #[test]
+    fn resize_with_test() {
+        let mut next = 1;
+        let a = DynArray::from([1, 2]).resize_with(4, || {
+            next += 1;
+            next
+        });
+        assert_eq!(&*a, &[1, 2, 2, 3]);
+
+        let a = a.resize_with(1, || unreachable!());
+        assert_eq!(&*a, &[1]);
+    }
+
+    #[test]
+    fn enumerate_test() {
+        let a = DynArray::from(["a", "b"]).enumerate();
+        assert_eq!(&*a, &[(0, "a"), (1, "b")]);
+    }
+
+    #[test]
+    fn partition_test() {
+        let (even, odd) = DynArray::from([1, 2, 3, 4]).partition(|x| x % 2 == 0);
+        assert_eq!(&*even, &[2, 4]);
+        assert_eq!(&*odd, &[1, 3]);
+
+        let (all, none) = DynArray::from(["a", "b"]).partition(|_| true);
+        assert_eq!(&*all, &["a", "b"]);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn filter_map_test() {
+        let a = DynArray::from([1, 2, 3, 4]).filter_map(|x| (x % 2 == 0).then_some(x * 10));
+        assert_eq!(&*a, &[20, 40]);
+
+        let count = Cell::new(0);
+        let b = DynArray::from_iter((0..4).map(|i| (i, DropCounter(&count))));
+        let b = b.filter_map(|(i, c)| (i < 1).then_some(c));
+        assert_eq!(b.len(), 1);
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn retain_mut_test() {
+        let count = Cell::new(0);
+
+        let a = DynArray::from_iter((1..5).map(|i| (i, DropCounter(&count))));
+        let a = a.retain_mut(|(i, _)| *i % 2 == 0);
+
+        assert_eq!(a.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [2, 4]);
+        assert_eq!(count.get(), 2);
+
+        drop(a);
+        assert_eq!(count.get(), 4);
+
+        let b = DynArray::from([1, 2, 3]).retain_mut(|x| {
+            *x *= 10;
+            *x != 20
+        });
+        assert_eq!(&*b, &[10, 30]);
+    }
+
+    #[test]
+    fn retain_test() {
+        let a = DynArray::from([1, 2, 3, 4, 5]);
+
+        let b = a.retain(|x| x % 2 == 1);
+        assert_eq!(&*b, &[1, 3, 5]);
+        assert_eq!(&*a, &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn dedup_test() {
+        let a = DynArray::from([1, 1, 2, 3, 3, 1]);
+        assert_eq!(&*a.dedup(), &[1, 2, 3, 1]);
+
+        let b: DynArray<i32> = DynArray::default();
+        assert!(b.dedup().is_empty());
+    }
+
+    #[test]
+    fn dedup_by_test() {
+        let a = DynArray::from(["a", "A", "b", "B", "a"]);
+        let deduped = a.dedup_by(|x, y| x.eq_ignore_ascii_case(y));
+
+        assert_eq!(&*deduped, &["a", "b", "a"]);
+        assert_eq!(a.len(), 5);
+    }
+
+    #[test]
+    fn split_into_test() {
+        let parts = DynArray::from([1, 2, 3, 4, 5, 6, 7]).split_into(3);
+
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), [3, 2, 2]);
+        assert_eq!(&*parts[0], &[1, 2, 3]);
+        assert_eq!(&*parts[1], &[4, 5]);
+        assert_eq!(&*parts[2], &[6, 7]);
+
+        let parts = DynArray::from(["a".to_string()]).split_into(2);
+        assert_eq!(&*parts[0], &["a"]);
+        assert!(parts[1].is_empty());
+    }
+
+    #[test]
+    fn split_into_overflow_test() {
+        let rc = Rc::new(());
+        let a = DynArray::from_elem(rc.clone(), 2);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| a.split_into(usize::MAX)));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn transpose_flat_test() {
+        let a = DynArray::from([1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(&*a.transpose_flat(3), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(&*a.transpose_flat(3).transpose_flat(2), &*a);
+        assert_eq!(&*a.transpose_flat(6), &*a);
+    }
+
+    #[test]
+    #[should_panic(expected = "width must be non-zero and divide the length")]
+    fn transpose_flat_width_test() {
+        let _ = DynArray::from([1, 2, 3]).transpose_flat(2);
+    }
+
+    #[test]
+    fn swap_remove_test() {
+        let (value, rest) = DynArray::from([1, 2, 3, 4]).swap_remove(0);
+        assert_eq!(value, 1);
+        assert_eq!(&*rest, &[4, 2, 3]);
+
+        let (value, rest) = rest.swap_remove(2);
+        assert_eq!(value, 3);
+        assert_eq!(&*rest, &[4, 2]);
+
+        let (value, rest) = DynArray::from(["a".to_string()]).swap_remove(0);
+        assert_eq!(value, "a");
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_remove index (is 2) should be < len (is 2)")]
+    fn swap_remove_bounds_test() {
+        let _ = DynArray::from([1, 2]).swap_remove(2);
+    }
+
+    #[test]
+    fn windows_map_test() {
+        let a = DynArray::from([1, 2, 3, 4]);
+
+        assert_eq!(&*a.windows_map(3, |w| w.iter().sum::<i32>()), &[6, 9]);
+        assert_eq!(&*a.windows_map(1, |w| w[0] * 2), &[2, 4, 6, 8]);
+        assert!(a.windows_map(5, |w| w.len()).is_empty());
+    }
+
+    #[test]
+    fn insert_sorted_test() {
+        let a = DynArray::from([1, 2, 4, 5]).insert_sorted(3);
+        assert_eq!(&*a, &[1, 2, 3, 4, 5]);
+
+        let a = a.insert_sorted(0).insert_sorted(6);
+        assert_eq!(&*a, &[0, 1, 2, 3, 4, 5, 6]);
+
+        let b = DynArray::default().insert_sorted("x".to_string());
+        assert_eq!(&*b, &["x"]);
+    }
+
+    #[test]
+    fn rotate_to_front_test() {
+        let mut a = DynArray::from([10, 20, 30, 40]);
+
+        a.rotate_to_front(2);
+        assert_eq!(&*a, &[30, 40, 10, 20]);
+
+        a.rotate_to_front(0);
+        assert_eq!(&*a, &[30, 40, 10, 20]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_to_front index (is 4) should be < len (is 4)")]
+    fn rotate_to_front_bounds_test() {
+        DynArray::from([10, 20, 30, 40]).rotate_to_front(4);
+    }
+
+    #[test]
+    fn new_rscan_test() {
+        let values = [3, 1, 4, 1, 5];
+
+        let suffix_sums = DynArray::new_rscan(values.len(), 0, |sum, idx| {
+            *sum += values[idx];
+            *sum
+        });
+
+        let expected: Vec<i32> = (0..values.len())
+            .map(|i| values[i..].iter().sum())
+            .collect();
+        assert_eq!(&*suffix_sums, &expected[..]);
+
+        let suffix_max = DynArray::new_rscan(values.len(), i32::MIN, |max, idx| {
+            *max = (*max).max(values[idx]);
+            *max
+        });
+        assert_eq!(&*suffix_max, &[5, 5, 5, 5, 5]);
+    }
+
+    #[test]
+    fn group_by_key_test() {
+        let a = DynArray::from([("a", 1), ("a", 2), ("b", 3)]);
+        let groups = a.group_by_key(|&(name, _)| name);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(&*groups[0], &[("a", 1), ("a", 2)]);
+        assert_eq!(&*groups[1], &[("b", 3)]);
+
+        assert!(DynArray::<u8>::default().group_by_key(|&x| x).is_empty());
+    }
+
+    #[test]
+    fn reshape_test() {
+        let a = DynArray::from([1u8, 2, 3, 4, 5, 6]);
+
+        let b = DynArray::<[u8; 3]>::try_from(a).unwrap();
+        assert_eq!(&*b, &[[1, 2, 3], [4, 5, 6]]);
+
+        let c = DynArray::from([1u8, 2, 3, 4]);
+        let c = DynArray::<[u8; 3]>::try_from(c).unwrap_err();
+        assert_eq!(&*c, &[1, 2, 3, 4]);
+
+        assert!(DynArray::<[u8; 0]>::try_from(c).is_err());
+    }
+
+    #[test]
+    fn macro_test() {
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+
+        let a = dyn_array![next(), next(), next()];
+        assert_eq!(&*a, &[1, 2, 3]);
+        assert_eq!(calls, 3);
+
+        let b = dyn_array!["x".to_string(); 4];
+        assert_eq!(b.len(), 4);
+        assert!(b.iter().all(|s| s == "x"));
+
+        let c: DynArray<u32> = dyn_array![];
+        assert!(c.is_empty());
+
+        let d: DynArray<u32> = dyn_array![0; 0];
+        assert!(d.is_empty());
+    }
+
+    /// counts how many times it has been dropped
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn from_array_test() {
+        let count = Cell::new(0);
+
+        let a = DynArray::from([(); 1000].map(|_| DropCounter(&count)));
+        assert_eq!(a.len(), 1000);
+        assert_eq!(count.get(), 0);
+
+        drop(a);
+        assert_eq!(count.get(), 1000);
+    }
+
+    #[test]
+    fn from_iter_panic_test() {
+        let count = Cell::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            DynArray::from_iter((0..10).map(|i| {
+                assert!(i != 3, "iterator panicked");
+                DropCounter(&count)
+            }))
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn new_panic_test() {
+        thread_local! {
+            static COUNT: Cell<usize> = const { Cell::new(0) };
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        struct PanickyDefault;
+
+        impl Default for PanickyDefault {
+            fn default() -> Self {
+                CALLS.set(CALLS.get() + 1);
+                assert!(CALLS.get() < 4, "default panicked");
+                PanickyDefault
+            }
+        }
+
+        impl Drop for PanickyDefault {
+            fn drop(&mut self) {
+                COUNT.set(COUNT.get() + 1);
+            }
+        }
+
+        let result = panic::catch_unwind(|| DynArray::<PanickyDefault>::new(10));
+
+        assert!(result.is_err());
+        assert_eq!(COUNT.get(), 3);
+    }
+
+    /// claims one element more than it yields
+    struct LyingIter<I>(I);
+
+    impl<I: Iterator> Iterator for LyingIter<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+    }
+
+    impl<I: ExactSizeIterator> ExactSizeIterator for LyingIter<I> {
+        fn len(&self) -> usize {
+            self.0.len() - 1
+        }
+    }
+
+    #[test]
+    fn from_iter_false_size_hint_test() {
+        let count = Cell::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            DynArray::from_iter(LyingIter((0..4).map(|_| DropCounter(&count))))
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(count.get(), 4);
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut x = 0;
+
+        struct DropTest<'a>(&'a mut i32);
+
+        impl Drop for DropTest<'_> {
+            fn drop(&mut self) {
+                *self.0 += 1;
+            }
+        }
+
+        let drop_test = DropTest(&mut x);
+
+        drop(drop_test);
+
+        assert_eq!(x, 1);
+
+        let mut drop_array = DynArray::new_uninit(1);
+
+        drop_array[0].write(DropTest(&mut x));
+
+        let drop_array = unsafe { drop_array.assume_init() };
+
+        drop(drop_array);
+
+        assert_eq!(x, 2);
+    }
+
+    /// panic-at-index-k checks shared by all filling constructors
+    mod panic_safety {
+        use std::cell::Cell;
+        use std::panic;
+
+        use crate::DynArray;
+
+        thread_local! {
+            static CREATED: Cell<usize> = const { Cell::new(0) };
+            static DROPPED: Cell<usize> = const { Cell::new(0) };
+            static PANIC_AT: Cell<usize> = const { Cell::new(usize::MAX) };
+        }
+
+        /// panics when created for the `PANIC_AT`-th time
+        struct Fragile;
+
+        impl Fragile {
+            fn create() -> Self {
+                let created = CREATED.get();
+                assert!(created != PANIC_AT.get(), "Fragile panicked");
+                CREATED.set(created + 1);
+                Fragile
+            }
+        }
+
+        impl Default for Fragile {
+            fn default() -> Self {
+                Fragile::create()
+            }
+        }
+
+        impl Clone for Fragile {
+            fn clone(&self) -> Self {
+                Fragile::create()
+            }
+        }
+
+        impl Drop for Fragile {
+            fn drop(&mut self) {
+                DROPPED.set(DROPPED.get() + 1);
+            }
+        }
+
+        fn assert_cleanup_at(k: usize, f: impl FnOnce() -> DynArray<Fragile> + panic::UnwindSafe) {
+            CREATED.set(0);
+            DROPPED.set(0);
+            PANIC_AT.set(k);
+
+            assert!(panic::catch_unwind(f).is_err());
+            assert_eq!(CREATED.get(), k);
+            assert_eq!(DROPPED.get(), k);
+        }
+
+        #[test]
+        fn new_test() {
+            for k in 0..5 {
+                assert_cleanup_at(k, || DynArray::new(8));
+            }
+        }
+
+        #[test]
+        fn from_elem_test() {
+            for k in 1..5 {
+                assert_cleanup_at(k, || DynArray::from_elem(Fragile::create(), 8));
+            }
+        }
+
+        #[test]
+        fn from_iter_test() {
+            for k in 0..5 {
+                assert_cleanup_at(k, || DynArray::from_iter((0..8).map(|_| Fragile::create())));
+            }
+        }
+
+        #[test]
+        fn new_rscan_test() {
+            for k in 0..5 {
+                assert_cleanup_at(k, || DynArray::new_rscan(8, (), |_, _| Fragile::create()));
+            }
+        }
+
+        #[test]
+        fn clone_test() {
+            for k in 3..6 {
+                assert_cleanup_at(k, || {
+                    let src = DynArray::<Fragile>::new(3);
+                    src.clone()
+                });
+            }
+
+            for k in 3..6 {
+                assert_cleanup_at(k, || {
+                    let src = DynArray::<Fragile>::new(3);
+                    DynArray::from(&src[..])
+                });
+            }
+        }
+
+        #[test]
+        fn write_clone_of_slice_test() {
+            for k in 3..6 {
+                assert_cleanup_at(k, || {
+                    let src = DynArray::<Fragile>::new(3);
+                    let mut a = DynArray::new_uninit(3);
+                    a.write_clone_of_slice(&src);
+                    unsafe { a.assume_init() }
+                });
+            }
+        }
+
+        #[test]
+        fn extend_with_test() {
+            for k in 3..6 {
+                assert_cleanup_at(k, || DynArray::new(2).extend_with(4, Fragile::create()));
+            }
+
+            for k in 2..5 {
+                assert_cleanup_at(k, || DynArray::new(2).extend_with_default(4));
+            }
+        }
+
+        #[test]
+        fn grow_test() {
+            for k in 2..5 {
+                assert_cleanup_at(k, || DynArray::new(2).grow(5));
+            }
+        }
+
+        #[test]
+        fn resize_with_test() {
+            for k in 2..5 {
+                assert_cleanup_at(k, || DynArray::new(2).resize_with(5, Fragile::create));
+            }
+        }
     }
 }
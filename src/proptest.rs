@@ -0,0 +1,58 @@
+//! proptest strategies for DynArray
+
+use alloc::vec::Vec;
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange, VecStrategy};
+use proptest::strategy::{Map, Strategy};
+
+use crate::DynArray;
+
+/// Create a strategy to generate DynArrays containing elements drawn from `element` and with a
+/// size range given by `size`
+///
+/// Shrinks like `proptest::collection::vec`, both in length and in elements.
+pub fn dyn_array<T: Strategy>(
+    element: T,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = DynArray<T::Value>> {
+    vec(element, size).prop_map(DynArray::from)
+}
+
+impl<T: Arbitrary> Arbitrary for DynArray<T>
+where
+    T::Strategy: 'static,
+{
+    type Parameters = <Vec<T> as Arbitrary>::Parameters;
+    type Strategy = Map<VecStrategy<T::Strategy>, fn(Vec<T>) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<Vec<T>>(args).prop_map(DynArray::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::dyn_array;
+    use crate::DynArray;
+
+    proptest! {
+        #[test]
+        fn sort_test(mut a in dyn_array(any::<i32>(), 0..100)) {
+            a.sort();
+            prop_assert!(a.windows(2).all(|w| w[0] <= w[1]));
+        }
+
+        #[test]
+        fn size_range_test(a in dyn_array(any::<u8>(), 3..=5)) {
+            prop_assert!((3..=5).contains(&a.len()));
+        }
+
+        #[test]
+        fn arbitrary_test(a in any::<DynArray<u16>>()) {
+            prop_assert_eq!(DynArray::from(a.to_vec()).into_vec(), a.to_vec());
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::DynArray;
+
+/// Generates and shrinks like `Vec<T>`: shrinking yields shorter arrays as well as arrays with
+/// shrunk elements
+impl<T: Arbitrary> Arbitrary for DynArray<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        DynArray::from(Vec::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_vec().shrink().map(DynArray::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{quickcheck, Arbitrary};
+
+    use crate::DynArray;
+
+    quickcheck! {
+        fn vec_round_trip(a: DynArray<u32>) -> bool {
+            let expected = a.to_vec();
+            DynArray::from(a.into_vec()).into_vec() == expected
+        }
+    }
+
+    #[test]
+    fn shrink_test() {
+        let a = DynArray::from([5u32, 10, 20]);
+        let shrunk: Vec<DynArray<u32>> = a.shrink().collect();
+
+        assert!(shrunk.iter().any(|b| b.len() < a.len()));
+        assert!(shrunk
+            .iter()
+            .any(|b| b.len() == a.len() && b.iter().zip(a.iter()).any(|(x, y)| x < y)));
+    }
+}
@@ -0,0 +1,73 @@
+use rand::distr::Distribution;
+use rand::{Rng, RngExt};
+
+use crate::{DynArray, PartialInit};
+
+impl<T> DynArray<T> {
+    /// shuffle the elements in place using a Fisher–Yates shuffle
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.len()).rev() {
+            let j = rng.random_range(0..=i);
+            self.swap(i, j);
+        }
+    }
+
+    /// allocate new DynArray of size `len` and fill each slot with a sample from `dist`
+    #[must_use]
+    pub fn from_distribution<D, R>(len: usize, dist: D, rng: &mut R) -> Self
+    where
+        D: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        let mut guard = PartialInit::new(len);
+
+        while !guard.is_full() {
+            guard.push(dist.sample(rng));
+        }
+
+        guard.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distr::Uniform;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::DynArray;
+
+    #[test]
+    fn shuffle_test() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let original = DynArray::from_iter(0..100u32);
+        let mut a = original.clone();
+
+        a.shuffle(&mut rng);
+        assert_ne!(&*a, &*original);
+
+        a.sort();
+        assert_eq!(&*a, &*original);
+
+        let mut empty = DynArray::<u32>::default();
+        empty.shuffle(&mut rng);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn from_distribution_test() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let dist = Uniform::new(0u32, 10).unwrap();
+
+        let a = DynArray::from_distribution(1000, dist, &mut rng);
+
+        assert_eq!(a.len(), 1000);
+        assert!(a.iter().all(|&x| x < 10));
+        assert!((0..10).all(|x| a.contains(&x)));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let b = DynArray::from_distribution(1000, dist, &mut rng);
+        assert_eq!(&*a, &*b);
+    }
+}
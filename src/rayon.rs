@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+use crate::DynArray;
+
+impl<'a, T: Sync> IntoParallelIterator for &'a DynArray<T> {
+    type Iter = rayon::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        <&[T]>::into_par_iter(self)
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut DynArray<T> {
+    type Iter = rayon::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        <&mut [T]>::into_par_iter(self)
+    }
+}
+
+/// Hands the backing storage over to rayon's `Vec` iterator without copying
+impl<T: Send> IntoParallelIterator for DynArray<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_vec().into_par_iter()
+    }
+}
+
+/// Collects into a `Vec` first, then finalizes into an exact-size DynArray
+impl<T: Send> FromParallelIterator<T> for DynArray<T> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let vec: Vec<T> = par_iter.into_par_iter().collect();
+        DynArray::from(vec)
+    }
+}
+
+impl<T: Ord + Send> DynArray<T> {
+    /// sort in parallel, preserving the order of equal elements
+    #[inline]
+    pub fn par_sort(&mut self) {
+        self.as_mut().par_sort();
+    }
+
+    /// sort in parallel, without preserving the order of equal elements
+    #[inline]
+    pub fn par_sort_unstable(&mut self) {
+        self.as_mut().par_sort_unstable();
+    }
+}
+
+impl<T: Clone + Send + Sync> DynArray<T> {
+    /// reduce the elements in parallel with an associative `f`
+    ///
+    /// `identity` has to be an identity value for `f`, since it may be used any number of times.
+    pub fn par_reduce<F>(&self, identity: T, f: F) -> T
+    where
+        F: Fn(T, T) -> T + Send + Sync,
+    {
+        self.into_par_iter().cloned().reduce(|| identity.clone(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+    use rayon::prelude::*;
+
+    use crate::DynArray;
+
+    #[test]
+    fn par_sum_test() {
+        let a = DynArray::from_iter((0..100_000u32).map(u64::from));
+        let expected: u64 = a.iter().sum();
+
+        assert_eq!(a.par_iter().sum::<u64>(), expected);
+        assert_eq!(a.into_par_iter().sum::<u64>(), expected);
+    }
+
+    #[test]
+    fn par_iter_mut_test() {
+        let mut a = DynArray::from_iter((0..1000u32).map(u64::from));
+
+        a.par_iter_mut().for_each(|x| *x *= 2);
+
+        for (i, x) in a.iter().enumerate() {
+            assert_eq!(*x, 2 * i as u64);
+        }
+    }
+
+    #[test]
+    fn par_collect_test() {
+        let a: DynArray<u64> = (0..10_000u64).into_par_iter().map(|x| x * x).collect();
+        let expected: Vec<u64> = (0..10_000u64).map(|x| x * x).collect();
+
+        assert_eq!(a.len(), expected.len());
+        assert_eq!(&*a, &expected[..]);
+    }
+
+    #[test]
+    fn par_sort_test() {
+        let mut values: Vec<u32> = (0..100_000).map(|x| x % 1000).collect();
+        values.shuffle(&mut StdRng::seed_from_u64(1));
+
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut a = DynArray::from(values.clone());
+        a.par_sort();
+        assert_eq!(&*a, &expected[..]);
+
+        let mut b = DynArray::from(values);
+        b.par_sort_unstable();
+        assert_eq!(&*b, &expected[..]);
+    }
+
+    #[test]
+    fn par_reduce_test() {
+        let a = DynArray::from_iter((0..100_000u32).map(u64::from));
+        let expected: u64 = a.iter().sum();
+
+        assert_eq!(a.par_reduce(0, |x, y| x + y), expected);
+        assert_eq!(DynArray::<u64>::default().par_reduce(0, |x, y| x + y), 0);
+    }
+}
@@ -0,0 +1,77 @@
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Serialize};
+
+use crate::{DynArray, PartialInit};
+
+/// Archived exactly like `Vec<T>`, so the archive can be accessed in place as a slice
+impl<T: Archive> Archive for DynArray<T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self, resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for DynArray<T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
+impl<T, D> Deserialize<DynArray<T>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<DynArray<T>, D::Error> {
+        let mut guard = PartialInit::new(self.len());
+
+        for elem in self.iter() {
+            guard.push(elem.deserialize(deserializer)?);
+        }
+
+        Ok(guard.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+    use rkyv::Archived;
+
+    use crate::DynArray;
+
+    #[test]
+    fn archive_round_trip_test() {
+        let a = DynArray::from([1u32, 2, 3, 4]);
+
+        let bytes = rkyv::to_bytes::<Error>(&a).unwrap();
+        let archived = rkyv::access::<ArchivedVec<Archived<u32>>, Error>(&bytes).unwrap();
+
+        assert_eq!(archived.len(), 4);
+        assert_eq!(archived[2], 3);
+
+        let b: DynArray<u32> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(&*a, &*b);
+    }
+
+    #[test]
+    fn matches_vec_test() {
+        let a = DynArray::from(["a".to_string(), "b".to_string()]);
+        let v = vec!["a".to_string(), "b".to_string()];
+
+        assert_eq!(
+            &*rkyv::to_bytes::<Error>(&a).unwrap(),
+            &*rkyv::to_bytes::<Error>(&v).unwrap()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{is_cautious_len, DynArray, PartialInit};
+
+impl<T: Serialize> Serialize for DynArray<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DynArray<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DynArrayVisitor(PhantomData))
+    }
+}
+
+struct DynArrayVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for DynArrayVisitor<T> {
+    type Value = DynArray<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // a hint is only trusted up to a cautious size, like serde does for `Vec`; past that
+        // the elements have to actually be there before memory is spent on them
+        let len = match seq.size_hint() {
+            Some(len) if is_cautious_len::<T>(len) => len,
+            _ => {
+                let mut vec = Vec::new();
+
+                while let Some(elem) = seq.next_element()? {
+                    vec.push(elem);
+                }
+
+                return Ok(DynArray::from(vec));
+            }
+        };
+
+        let mut guard = PartialInit::new(len);
+
+        while !guard.is_full() {
+            match seq.next_element()? {
+                Some(elem) => guard.push(elem),
+                None => return Err(A::Error::invalid_length(guard.initialized, &self)),
+            }
+        }
+
+        if seq.next_element::<T>()?.is_some() {
+            return Err(A::Error::invalid_length(len + 1, &self));
+        }
+
+        Ok(guard.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::value::{Error, SeqAccessDeserializer};
+    use serde::de::{DeserializeSeed, SeqAccess};
+    use serde::Deserialize;
+
+    use crate::DynArray;
+
+    #[test]
+    fn json_round_trip_test() {
+        let a = DynArray::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"["a","b","c"]"#);
+
+        let b: DynArray<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&*a, &*b);
+
+        let empty: DynArray<u32> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn element_error_test() {
+        let result = serde_json::from_str::<DynArray<String>>(r#"["a", "b", 3]"#);
+        assert!(result.is_err());
+    }
+
+    /// sequence that claims a length it doesn't deliver
+    struct LyingSeq {
+        elems: std::vec::IntoIter<String>,
+        hint: usize,
+    }
+
+    impl<'de> SeqAccess<'de> for LyingSeq {
+        type Error = Error;
+
+        fn next_element_seed<S: DeserializeSeed<'de>>(
+            &mut self,
+            seed: S,
+        ) -> Result<Option<S::Value>, Error> {
+            use serde::de::IntoDeserializer;
+
+            self.elems
+                .next()
+                .map(|elem| seed.deserialize(elem.into_deserializer()))
+                .transpose()
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.hint)
+        }
+    }
+
+    #[test]
+    fn length_mismatch_test() {
+        let elems = vec!["a".to_string(), "b".to_string()];
+
+        let too_short = LyingSeq {
+            elems: elems.clone().into_iter(),
+            hint: 3,
+        };
+        let result = DynArray::<String>::deserialize(SeqAccessDeserializer::new(too_short));
+        assert!(result.is_err());
+
+        let too_long = LyingSeq {
+            elems: elems.into_iter(),
+            hint: 1,
+        };
+        let result = DynArray::<String>::deserialize(SeqAccessDeserializer::new(too_long));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn huge_hint_test() {
+        let huge = LyingSeq {
+            elems: vec!["a".to_string(), "b".to_string()].into_iter(),
+            hint: 1 << 45,
+        };
+
+        let a = DynArray::<String>::deserialize(SeqAccessDeserializer::new(huge)).unwrap();
+        assert_eq!(&*a, &["a", "b"]);
+    }
+}